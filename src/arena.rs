@@ -0,0 +1,139 @@
+//! Module `arena.rs`
+//!
+//! Ce module regroupe la géométrie de l'arène torique : un objet qui sort d'un côté de l'écran
+//! réapparaît du côté opposé. Tous les objets stellaires passent par ces fonctions pour se
+//! déplacer et mesurer leurs distances, afin que le repliement soit calculé au même endroit.
+
+use macroquad::math::{vec2, Vec2};
+
+/// Replie une coordonnée dans l'intervalle `[0, max)`.
+///
+/// # Arguments
+/// * `coord` - La coordonnée à replier, éventuellement très loin hors de l'arène.
+/// * `max` - La largeur ou la hauteur de l'arène.
+///
+/// # Retourne
+/// * `f32` - La coordonnée équivalente dans l'arène, toujours comprise dans `[0, max)`.
+pub fn wrap_coord(coord: f32, max: f32) -> f32 {
+    let wrapped = coord.rem_euclid(max);
+    // Un reste négatif minuscule peut être arrondi à `max` : il correspond au bord `0`.
+    if wrapped >= max {
+        0.0
+    } else {
+        wrapped
+    }
+}
+
+/// Replie une position dans l'arène.
+///
+/// # Arguments
+/// * `position` - La position à replier.
+/// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+///
+/// # Retourne
+/// * `Vec2` - La position équivalente, dont chaque composante est dans `[0, bounds)`.
+pub fn wrap_position(position: Vec2, bounds: Vec2) -> Vec2 {
+    vec2(
+        wrap_coord(position.x, bounds.x),
+        wrap_coord(position.y, bounds.y),
+    )
+}
+
+/// Déplace un objet dans l'arène en repliant sa nouvelle position.
+///
+/// La position précédente est décalée du même repliement que la nouvelle, pour que
+/// l'interpolation de l'affichage ne traverse pas tout l'écran quand l'objet passe un bord.
+///
+/// # Arguments
+/// * `position` - La position actuelle de l'objet.
+/// * `displacement` - Le déplacement à appliquer.
+/// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+///
+/// # Retourne
+/// * `(Vec2, Vec2)` - La nouvelle position repliée et la position précédente correspondante.
+pub fn move_wrapped(position: Vec2, displacement: Vec2, bounds: Vec2) -> (Vec2, Vec2) {
+    let moved = position + displacement;
+    let wrapped = wrap_position(moved, bounds);
+    (wrapped, position + (wrapped - moved))
+}
+
+/// Calcule le plus court déplacement de `from` vers `to` dans l'arène.
+///
+/// # Arguments
+/// * `from` - Position de départ.
+/// * `to` - Position d'arrivée.
+/// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+///
+/// # Retourne
+/// * `Vec2` - Le déplacement, dont chaque composante est comprise entre `-bounds / 2` et `bounds / 2`.
+pub fn wrapped_delta(from: Vec2, to: Vec2, bounds: Vec2) -> Vec2 {
+    let delta = to - from;
+    delta - bounds * (delta / bounds).round()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::SmallRng;
+    use ::rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_wrap_coord_reenters_on_the_opposite_side() {
+        assert_eq!(wrap_coord(-3.0, 100.0), 97.0);
+        assert_eq!(wrap_coord(103.0, 100.0), 3.0);
+        assert_eq!(wrap_coord(100.0, 100.0), 0.0);
+        assert_eq!(wrap_coord(42.0, 100.0), 42.0);
+        assert_eq!(wrap_coord(-f32::EPSILON / 4.0, 100.0), 0.0);
+    }
+
+    /// Propriété : quelle que soit la vitesse, même si l'objet traverse plusieurs fois l'écran
+    /// en un pas, la position repliée reste dans `[0, max)` et ne diffère de la position réelle
+    /// que d'un nombre entier de largeurs d'arène.
+    #[test]
+    fn test_move_wrapped_always_stays_in_arena() {
+        let mut rng = SmallRng::seed_from_u64(10);
+        for _ in 0..100_000 {
+            let bounds = vec2(rng.gen_range(1.0..2000.0), rng.gen_range(1.0..2000.0));
+            let position = vec2(rng.gen_range(0.0..bounds.x), rng.gen_range(0.0..bounds.y));
+            let max_speed = 10f32.powi(rng.gen_range(0..7));
+            let velocity = vec2(
+                rng.gen_range(-max_speed..=max_speed),
+                rng.gen_range(-max_speed..=max_speed),
+            );
+            let dt = rng.gen_range(0.0..1.0);
+
+            let (wrapped, previous) = move_wrapped(position, velocity * dt, bounds);
+
+            assert!(
+                (0.0..bounds.x).contains(&wrapped.x),
+                "{wrapped} hors de {bounds}"
+            );
+            assert!(
+                (0.0..bounds.y).contains(&wrapped.y),
+                "{wrapped} hors de {bounds}"
+            );
+            let turns = (position + velocity * dt - wrapped) / bounds;
+            let tolerance = (velocity * dt).abs() * 1e-5 / bounds + 1e-3;
+            assert!((turns - turns.round()).abs().cmple(tolerance).all());
+            // L'objet s'est déplacé du même vecteur depuis sa position précédente.
+            assert!(
+                (wrapped - previous - velocity * dt).length() <= velocity.length() * 1e-5 + 1e-2
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrapped_delta_takes_the_shortest_way() {
+        let bounds = vec2(100.0, 50.0);
+        let mut rng = SmallRng::seed_from_u64(11);
+        for _ in 0..10_000 {
+            let from = vec2(rng.gen_range(0.0..100.0), rng.gen_range(0.0..50.0));
+            let to = vec2(rng.gen_range(0.0..100.0), rng.gen_range(0.0..50.0));
+            let delta = wrapped_delta(from, to, bounds);
+
+            assert!(delta.x.abs() <= 50.0 && delta.y.abs() <= 25.0);
+            let arrival = wrap_position(from + delta, bounds);
+            assert!(wrapped_delta(arrival, to, bounds).length() < 1e-3);
+        }
+    }
+}
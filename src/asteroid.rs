@@ -2,41 +2,76 @@
 //!
 //! Ce module définit la structure `Asteroid`, représentant un astéroïde dans le jeu.
 
+use crate::arena;
 use crate::stellarobject::StellarObject;
-use ::rand::{thread_rng, Rng};
-use macroquad::prelude::*;
+use crate::wave::Wave;
+use ::rand::distributions::{Distribution, WeightedIndex};
+use ::rand::Rng;
+use macroquad::math::{vec2, Vec2};
 use std::f32::consts::PI;
 
+/// Impact qui divise un astéroïde : point de contact et vitesse de l'objet qui l'a touché.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Impact {
+    pub point: Vec2,    // Position du projectile ou du vaisseau au moment du choc
+    pub velocity: Vec2, // Vitesse du projectile ou du vaisseau, en pixels par seconde
+}
+
 /// Structure `Asteroid` représentant un astéroïde dans le jeu.
 ///
-/// L'astéroïde possède une position, une vitesse, un rayon et une texture. Il se déplace à une vitesse
-/// constante et, lorsqu'il dépasse les limites de l'écran, réapparaît du côté opposé.
-pub struct Asteroid<'a> {
-    radius: f32,            // Rayon de l'astéroïde
-    position: Vec2,         // Position de l'astéroïde
-    speed: Vec2,            // Vitesse et direction du déplacement de l'astéroïde
-    texture: &'a Texture2D, // Texture de l'astéroïde
+/// L'astéroïde possède une position, une vitesse, un rayon et une masse. Il se déplace
+/// en ligne droite, rebondit sur les autres astéroïdes et, lorsqu'il dépasse les limites de
+/// l'arène, réapparaît du côté opposé.
+pub struct Asteroid {
+    radius: f32,             // Rayon de l'astéroïde
+    position: Vec2,          // Position de l'astéroïde
+    previous_position: Vec2, // Position de l'astéroïde avant le dernier pas
+    speed: Vec2, // Vitesse et direction du déplacement de l'astéroïde (en pixels par seconde)
+    mass: f32,   // Masse de l'astéroïde, partagée entre ses fragments lorsqu'il se divise
 }
 
-impl<'a> Asteroid<'a> {
+impl Asteroid {
     // Taille de l'astéroïde par défaut
     pub const ASTEROID_TAILLE: f32 = 60.0;
+    // Part de la vitesse du projectile transmise aux fragments lors d'une division
+    pub const IMPACT_TRANSFER: f32 = 0.15;
+    // Vitesse d'écartement des fragments, en fraction de la vitesse de l'astéroïde divisé
+    pub const SPLIT_SPREAD: f32 = 0.75;
+    // Vitesse à laquelle un astéroïde rapporte le double de ses points de base, en pixels par seconde
+    pub const SCORE_REFERENCE_SPEED: f32 = 100.0;
 
     /// Crée un nouvel astéroïde avec une position, une vitesse et un rayon aléatoires.
     ///
     /// # Arguments
     ///
-    /// * `texture` - La texture à utiliser pour dessiner l'astéroïde.
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    /// * `wave` - La vague courante, qui fixe la plage de vitesses et la répartition des tailles.
+    /// * `rng` - Le générateur aléatoire de la partie.
     ///
     /// # Retourne
     ///
     /// Un nouvel astéroïde avec des propriétés générées aléatoirement.
-    pub fn new(texture: &'a Texture2D) -> Self {
+    pub fn new(bounds: Vec2, wave: &Wave, rng: &mut impl Rng) -> Self {
+        let position = Self::new_alea_pos(bounds, rng);
+        let radius = Self::new_alea_radius(wave.size_weights, rng);
+        Self {
+            radius,
+            position,
+            previous_position: position,
+            speed: Self::new_alea_speed(wave.speed_range, rng),
+            mass: radius * radius,
+        }
+    }
+
+    /// Crée un astéroïde aux propriétés fixées, pour placer précisément les objets d'un test.
+    #[cfg(test)]
+    pub fn new_at(position: Vec2, radius: f32, speed: Vec2) -> Self {
         Self {
-            radius: Self::new_alea_radius(),
-            position: Self::new_alea_pos(),
-            speed: Self::new_alea_speed(),
-            texture,
+            radius,
+            position,
+            previous_position: position,
+            speed,
+            mass: radius * radius,
         }
     }
 
@@ -44,151 +79,236 @@ impl<'a> Asteroid<'a> {
     ///
     /// # Arguments
     ///
-    /// * `n` - Le nombre d'astéroïdes à générer.
-    /// * `texture` - La texture à utiliser pour chaque astéroïde.
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    /// * `wave` - La vague à générer, qui fixe le nombre d'astéroïdes.
+    /// * `rng` - Le générateur aléatoire de la partie.
     ///
     /// # Retourne
     ///
-    /// * `Vec<Asteroid<'a>>` - Un vecteur contenant `n` astéroïdes.
-    pub fn generate_asteroid(n: usize, texture: &'a Texture2D) -> Vec<Asteroid<'a>> {
-        (0..n).map(|_| Asteroid::new(texture)).collect()
+    /// * `Vec<Asteroid>` - Un vecteur contenant les astéroïdes de la vague.
+    pub fn generate_asteroid(bounds: Vec2, wave: &Wave, rng: &mut impl Rng) -> Vec<Asteroid> {
+        (0..wave.count)
+            .map(|_| Asteroid::new(bounds, wave, rng))
+            .collect()
     }
 
-    /// Divise l'astéroïde en deux plus petits astéroïdes si sa taille le permet.
+    /// Divise l'astéroïde en plus petits astéroïdes si sa taille le permet.
+    ///
+    /// Les fragments sont répartis régulièrement autour du centre de l'astéroïde, sur un cercle
+    /// perpendiculaire à la direction de l'impact, et s'écartent les uns des autres à une vitesse
+    /// proportionnelle à celle de l'astéroïde. Les fragments se partagent la masse de l'astéroïde
+    /// et ces vitesses d'écartement se compensent : ils emportent toute sa quantité de mouvement,
+    /// augmentée de la part transmise par le projectile (`IMPACT_TRANSFER`), qui les repousse loin
+    /// du point d'impact. La vitesse de chaque fragment est enfin limitée à `max_speed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `impact` - Le point et la vitesse du projectile ou du vaisseau qui a touché l'astéroïde.
+    /// * `large_fragments` - Nombre de fragments d'un grand astéroïde (au moins 2). Les astéroïdes
+    ///   moyens se divisent toujours en deux.
+    /// * `max_speed` - Vitesse maximale d'un fragment, en pixels par seconde.
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    /// * `rng` - Le générateur aléatoire de la partie, utilisé si l'impact a lieu au centre exact.
     ///
     /// # Retourne
     ///
-    /// * `Vec<Asteroid<'a>>` - Un vecteur contenant les deux nouveaux astéroïdes créés, ou un vecteur vide si l'astéroïde
-    ///     ne peut pas être divisé car trop petit.
-    pub fn split(&self) -> Vec<Asteroid<'a>> {
+    /// * `Vec<Asteroid>` - Un vecteur contenant les nouveaux astéroïdes créés, ou un vecteur vide si l'astéroïde
+    ///   ne peut pas être divisé car trop petit.
+    pub fn split(
+        &self,
+        impact: &Impact,
+        large_fragments: usize,
+        max_speed: f32,
+        bounds: Vec2,
+        rng: &mut impl Rng,
+    ) -> Vec<Asteroid> {
         let current_radius = self.radius();
+        if current_radius <= Self::ASTEROID_TAILLE / 2.0 / 2.0 {
+            return vec![];
+        }
 
-        if current_radius > Self::ASTEROID_TAILLE / 2.0 / 2.0 {
-            let new_radius = current_radius / 2.0;
+        let new_radius = current_radius / 2.0;
+        let count = if current_radius >= Self::ASTEROID_TAILLE {
+            large_fragments.max(2)
+        } else {
+            2
+        };
 
-            let mut rng = thread_rng();
-            let angle_offset: f32 = rng.gen_range(0.0..(2.0 * PI));
+        // Direction allant du point d'impact vers le centre de l'astéroïde.
+        let axis = arena::wrapped_delta(impact.point, self.position, bounds)
+            .try_normalize()
+            .unwrap_or_else(|| Vec2::from_angle(rng.gen_range(0.0..(2.0 * PI))));
+        // Distance au centre pour que des fragments voisins se touchent sans se chevaucher.
+        let distance = new_radius / (PI / count as f32).sin();
+        let drift = self.speed + impact.velocity * Self::IMPACT_TRANSFER;
+        let spread = self.speed.length() * Self::SPLIT_SPREAD;
 
-            let asteroid1 = Asteroid {
-                radius: new_radius,
-                position: self.position + vec2(new_radius / 2.0, 0.0),
-                speed: Vec2::from_angle(angle_offset).normalize() * self.speed.length(),
-                texture: self.texture,
-            };
+        (0..count)
+            .map(|index| {
+                let angle = PI / 2.0 + 2.0 * PI * index as f32 / count as f32;
+                let direction = Vec2::from_angle(angle).rotate(axis);
+                let position = arena::wrap_position(self.position + direction * distance, bounds);
+                Asteroid {
+                    radius: new_radius,
+                    position,
+                    previous_position: position,
+                    speed: (drift + direction * spread).clamp_length_max(max_speed),
+                    mass: self.mass / count as f32,
+                }
+            })
+            .collect()
+    }
 
-            let asteroid2 = Asteroid {
-                radius: new_radius,
-                position: self.position - vec2(new_radius / 2.0, 0.0),
-                speed: Vec2::from_angle(angle_offset + PI / 2.0).normalize() * self.speed.length(),
-                texture: self.texture,
-            };
+    /// Retourne la vitesse de l'astéroïde, en pixels par seconde.
+    pub fn speed(&self) -> Vec2 {
+        self.speed
+    }
 
-            vec![asteroid1, asteroid2]
+    /// Retourne les points rapportés par la destruction de l'astéroïde.
+    ///
+    /// Comme dans le jeu d'arcade, les petits astéroïdes, plus difficiles à toucher, rapportent
+    /// plus que les grands. Les points augmentent en plus avec la vitesse de l'astéroïde.
+    pub fn points(&self) -> i32 {
+        let base = if self.radius <= Self::ASTEROID_TAILLE / 2.0 / 2.0 {
+            100
+        } else if self.radius <= Self::ASTEROID_TAILLE / 2.0 {
+            50
         } else {
-            vec![]
+            20
+        };
+        let speed_factor = 1.0 + self.speed.length() / Self::SCORE_REFERENCE_SPEED;
+        (base as f32 * speed_factor).round() as i32
+    }
+
+    /// Modifie la vitesse de l'astéroïde sous l'effet d'une accélération extérieure.
+    ///
+    /// # Arguments
+    ///
+    /// * `acceleration` - L'accélération subie, en pixels par seconde au carré.
+    /// * `dt` - La durée pendant laquelle elle s'applique, en secondes.
+    pub fn accelerate(&mut self, acceleration: Vec2, dt: f32) {
+        self.speed += acceleration * dt;
+    }
+
+    /// Retourne la masse de l'astéroïde : proportionnelle à sa surface pour un astéroïde généré,
+    /// partagée à parts égales entre les fragments d'un astéroïde divisé.
+    pub fn mass(&self) -> f32 {
+        self.mass
+    }
+
+    /// Fait rebondir deux astéroïdes qui se chevauchent l'un sur l'autre.
+    ///
+    /// Les deux astéroïdes sont d'abord écartés le long de la droite qui joint leurs centres, le
+    /// plus léger se déplaçant davantage, jusqu'à ce qu'ils ne fassent plus que se toucher. S'ils
+    /// se rapprochent encore, leurs vitesses échangent ensuite une impulsion qui conserve la
+    /// quantité de mouvement.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - L'autre astéroïde.
+    /// * `restitution` - Coefficient de restitution : 1 pour un choc élastique, 0 pour un choc
+    ///   où les astéroïdes restent collés l'un à l'autre.
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    pub fn bounce(&mut self, other: &mut Asteroid, restitution: f32, bounds: Vec2) {
+        let delta = arena::wrapped_delta(self.position, other.position, bounds);
+        let overlap = self.radius + other.radius - delta.length();
+        if overlap <= 0.0 {
+            return;
+        }
+        // Des centres confondus n'indiquent aucune direction : on les sépare horizontalement.
+        let normal = delta.try_normalize().unwrap_or(Vec2::X);
+        let total_mass = self.mass() + other.mass();
+
+        // Séparation : la position précédente suit, pour ne pas fausser l'interpolation.
+        let self_shift = -normal * overlap * other.mass() / total_mass;
+        let other_shift = normal * overlap * self.mass() / total_mass;
+        self.shift(self_shift, bounds);
+        other.shift(other_shift, bounds);
+
+        // Impulsion le long de la normale, seulement si les astéroïdes se rapprochent.
+        let approach_speed = (other.speed - self.speed).dot(normal);
+        if approach_speed < 0.0 {
+            let impulse =
+                -(1.0 + restitution) * approach_speed / (1.0 / self.mass() + 1.0 / other.mass());
+            self.speed -= normal * impulse / self.mass();
+            other.speed += normal * impulse / other.mass();
         }
     }
 
+    /// Décale l'astéroïde (et sa position précédente) en restant dans l'arène.
+    fn shift(&mut self, offset: Vec2, bounds: Vec2) {
+        let position = arena::wrap_position(self.position + offset, bounds);
+        self.previous_position += position - self.position;
+        self.position = position;
+    }
+
     /// Génère un rayon aléatoire pour un nouvel astéroïde.
     ///
+    /// # Arguments
+    ///
+    /// * `size_weights` - Poids des tailles petite, moyenne et grande. Si les poids sont tous nuls,
+    ///   les trois tailles sont équiprobables.
+    /// * `rng` - Le générateur aléatoire de la partie.
+    ///
     /// # Retourne
     ///
     /// * `f32` - Un flottant représentant un rayon aléatoire parmi trois tailles possibles.
-    fn new_alea_radius() -> f32 {
-        let mut rng = thread_rng();
-        let num: f32 = rng.gen_range(1..=3) as f32;
+    fn new_alea_radius(size_weights: [f32; 3], rng: &mut impl Rng) -> f32 {
+        let num = match WeightedIndex::new(size_weights) {
+            Ok(distribution) => distribution.sample(rng) + 1,
+            Err(_) => rng.gen_range(1..=3),
+        };
         match num {
-            1.0 => Self::ASTEROID_TAILLE / 2.0 / 2.0,
-            2.0 => Self::ASTEROID_TAILLE / 2.0,
+            1 => Self::ASTEROID_TAILLE / 2.0 / 2.0,
+            2 => Self::ASTEROID_TAILLE / 2.0,
             _ => Self::ASTEROID_TAILLE,
         }
     }
 
-    /// Génère une position aléatoire pour un nouvel astéroïde à la périphérie de l'écran.
+    /// Génère une position aléatoire pour un nouvel astéroïde à la périphérie de l'arène.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    /// * `rng` - Le générateur aléatoire de la partie.
     ///
     /// # Retourne
     ///
-    /// * `Vec2` - Une position aléatoire sur l'écran, choisie parmi les bords représentée par un vecteur à 2 dimensions.
-    fn new_alea_pos() -> Vec2 {
-        let mut rng = thread_rng();
-
+    /// * `Vec2` - Une position aléatoire dans l'arène, choisie parmi les bords représentée par un vecteur à 2 dimensions.
+    fn new_alea_pos(bounds: Vec2, rng: &mut impl Rng) -> Vec2 {
         let nearpos: f32 = rng.gen_range(Self::ASTEROID_TAILLE / 2.0..=Self::ASTEROID_TAILLE);
         let nearside = rng.gen_range(1..=4);
         let xpos: f32 = match nearside {
-            2 => screen_width() - nearpos,
+            2 => bounds.x - nearpos,
             4 => nearpos,
-            _ => rng.gen_range(0.0..=screen_width()),
+            _ => rng.gen_range(0.0..=bounds.x),
         };
         let ypos: f32 = match nearside {
             1 => nearpos,
-            3 => screen_height() - nearpos,
-            _ => rng.gen_range(0.0..=screen_height()),
+            3 => bounds.y - nearpos,
+            _ => rng.gen_range(0.0..=bounds.y),
         };
         vec2(xpos, ypos)
     }
 
     /// Génère une vitesse aléatoire pour un astéroïde, avec une direction aléatoire.
     ///
-    /// # Retourne
-    ///
-    /// * `Vec2` - Une vitesse aléatoire, représentée par un vecteur à 2 dimensions.
-    fn new_alea_speed() -> Vec2 {
-        let mut rng = thread_rng();
-        let angle: f32 = rng.gen_range(0.0..=(2.0 * PI));
-        Vec2::from_angle(angle)
-    }
-
-    /// Dessine l'astéroïde à l'écran en utilisant sa texture et sa position.
-    pub fn draw(&self) {
-        draw_texture_ex(
-            self.texture,
-            self.position.x - self.radius,
-            self.position.y - self.radius,
-            WHITE,
-            DrawTextureParams {
-                dest_size: Some(vec2(self.radius * 2.0, self.radius * 2.0)),
-                ..Default::default()
-            },
-        );
-    }
-
-    /// Contraint la position de l'astéroïde pour qu'il reste à l'intérieur des limites de l'écran.
-    ///
-    /// # Arguments
-    ///
-    /// * `pos` - La position actuelle de l'astéroïde.
-    ///
-    /// # Retourne
-    ///
-    /// * `Vec2` - Un vecteur à 2 dimensions représentant la position ajustée de l'astéroïde, qui sera toujours dans les limites de l'écran.
-    fn bound_pos(mut pos: Vec2) -> Vec2 {
-        pos.x = Self::bound_to(pos.x, screen_width());
-        pos.y = Self::bound_to(pos.y, screen_height());
-        pos
-    }
-
-    /// Ajuste une coordonnée pour qu'elle reste à l'intérieur des limites spécifiées.
-    ///
     /// # Arguments
     ///
-    /// * `coord` - La coordonnée à ajuster.
-    /// * `max` - La valeur maximale (largeur ou hauteur de l'écran).
+    /// * `speed_range` - Vitesses minimale et maximale, en pixels par seconde.
+    /// * `rng` - Le générateur aléatoire de la partie.
     ///
     /// # Retourne
     ///
-    /// * `f32` - Un flottant qui définie la coordonnée ajustée.
-    fn bound_to(coord: f32, max: f32) -> f32 {
-        if coord < 0.0 {
-            max - coord
-        } else if coord > max {
-            coord - max
-        } else {
-            coord
-        }
+    /// * `Vec2` - Une vitesse aléatoire en pixels par seconde, représentée par un vecteur à 2 dimensions.
+    fn new_alea_speed(speed_range: (f32, f32), rng: &mut impl Rng) -> Vec2 {
+        let angle: f32 = rng.gen_range(0.0..=(2.0 * PI));
+        let speed: f32 = rng.gen_range(speed_range.0..=speed_range.1);
+        Vec2::from_angle(angle) * speed
     }
 }
 
-impl<'a> StellarObject for Asteroid<'a> {
+impl StellarObject for Asteroid {
     /// Retourne la position actuelle de l'astéroïde.
     ///
     /// # Retourne
@@ -198,12 +318,17 @@ impl<'a> StellarObject for Asteroid<'a> {
         self.position
     }
 
+    /// Retourne la position de l'astéroïde avant le dernier pas, dans le repère de `position()`.
+    fn previous_position(&self) -> Vec2 {
+        self.previous_position
+    }
+
     /// Met à jour la position de l'astéroïde en fonction de sa vitesse.
     ///
-    /// La position est ajustée pour rester à l'intérieur des limites de l'écran.
-    fn update_position(&mut self) {
-        self.position += self.speed;
-        self.position = Self::bound_pos(self.position);
+    /// La position est ajustée pour rester à l'intérieur des limites de l'arène.
+    fn update_position(&mut self, bounds: Vec2, dt: f32) {
+        (self.position, self.previous_position) =
+            arena::move_wrapped(self.position, self.speed * dt, bounds);
     }
 
     /// Retourne le rayon de l'astéroïde.
@@ -215,3 +340,147 @@ impl<'a> StellarObject for Asteroid<'a> {
         self.radius
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    fn momentum(a: &Asteroid, b: &Asteroid) -> Vec2 {
+        a.speed * a.mass() + b.speed * b.mass()
+    }
+
+    #[test]
+    fn test_bounce_conserves_momentum() {
+        let bounds = vec2(1000.0, 1000.0);
+        let mut big = Asteroid::new_at(vec2(500.0, 500.0), 60.0, vec2(50.0, 10.0));
+        let mut small = Asteroid::new_at(vec2(580.0, 520.0), 30.0, vec2(-80.0, 0.0));
+        let before = momentum(&big, &small);
+        let energy = |a: &Asteroid, b: &Asteroid| {
+            a.mass() * a.speed.length_squared() + b.mass() * b.speed.length_squared()
+        };
+        let energy_before = energy(&big, &small);
+
+        big.bounce(&mut small, 1.0, bounds);
+
+        assert!((momentum(&big, &small) - before).length() < before.length() * 1e-4);
+        assert!((energy(&big, &small) - energy_before).abs() < energy_before * 1e-4);
+        // Le plus léger recule davantage et les deux astéroïdes ne font plus que se toucher.
+        assert!(small.speed.x > 0.0);
+        assert!(big.position.distance(small.position) >= 90.0 - 1e-3);
+    }
+
+    #[test]
+    fn test_bounce_separates_overlapping_pair_across_edge() {
+        let bounds = vec2(1000.0, 1000.0);
+        let mut first = Asteroid::new_at(vec2(995.0, 500.0), 30.0, Vec2::ZERO);
+        let mut second = Asteroid::new_at(vec2(5.0, 500.0), 30.0, Vec2::ZERO);
+
+        assert!(first.check_collision(&second, bounds));
+        first.bounce(&mut second, 0.0, bounds);
+
+        let gap = arena::wrapped_delta(first.position, second.position, bounds).length();
+        assert!(gap >= first.radius + second.radius - 1e-3);
+        assert!((0.0..bounds.x).contains(&first.position.x));
+        assert!((0.0..bounds.x).contains(&second.position.x));
+    }
+
+    #[test]
+    fn test_split_conserves_momentum() {
+        let bounds = vec2(1000.0, 1000.0);
+        let parent = Asteroid::new_at(
+            vec2(500.0, 500.0),
+            Asteroid::ASTEROID_TAILLE,
+            vec2(40.0, 30.0),
+        );
+        let impact = Impact {
+            point: vec2(440.0, 500.0),
+            velocity: vec2(300.0, 0.0),
+        };
+        let mut rng = SmallRng::seed_from_u64(3);
+
+        for count in [2, 3, 5] {
+            let fragments = parent.split(&impact, count, f32::INFINITY, bounds, &mut rng);
+            assert_eq!(fragments.len(), count);
+
+            let momentum: Vec2 = fragments.iter().map(|f| f.speed * f.mass()).sum();
+            let expected =
+                (parent.speed + impact.velocity * Asteroid::IMPACT_TRANSFER) * parent.mass();
+            assert!((momentum - expected).length() < 1e-3 * expected.length());
+            let mass: f32 = fragments.iter().map(Asteroid::mass).sum();
+            assert!((mass - parent.mass()).abs() < 1e-3);
+
+            // Les fragments s'éloignent du point d'impact et ne se chevauchent pas.
+            assert!((momentum / mass).x > parent.speed.x);
+            for (i, a) in fragments.iter().enumerate() {
+                for b in &fragments[i + 1..] {
+                    let gap = a.position.distance(b.position);
+                    assert!(gap >= a.radius + b.radius - 1e-3);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_small_and_fast_asteroids_are_worth_more() {
+        let large = Asteroid::new_at(Vec2::ZERO, Asteroid::ASTEROID_TAILLE, Vec2::ZERO);
+        let small = Asteroid::new_at(Vec2::ZERO, Asteroid::ASTEROID_TAILLE / 4.0, Vec2::ZERO);
+        let fast = Asteroid::new_at(
+            Vec2::ZERO,
+            Asteroid::ASTEROID_TAILLE / 4.0,
+            vec2(Asteroid::SCORE_REFERENCE_SPEED, 0.0),
+        );
+        assert_eq!(large.points(), 20);
+        assert_eq!(small.points(), 100);
+        assert_eq!(fast.points(), 200);
+    }
+
+    #[test]
+    fn test_repeated_splits_keep_fragments_below_max_speed() {
+        let bounds = vec2(1000.0, 1000.0);
+        let max_speed = 200.0;
+        let mut rocks = vec![Asteroid::new_at(
+            vec2(500.0, 500.0),
+            Asteroid::ASTEROID_TAILLE,
+            vec2(180.0, 0.0),
+        )];
+        let mut rng = SmallRng::seed_from_u64(5);
+
+        // Chaque génération est touchée de face par un missile, jusqu'aux plus petits fragments.
+        while !rocks.is_empty() {
+            rocks = rocks
+                .iter()
+                .flat_map(|rock| {
+                    let impact = Impact {
+                        point: rock.position - vec2(rock.radius, 0.0),
+                        velocity: vec2(300.0, 0.0),
+                    };
+                    rock.split(&impact, 3, max_speed, bounds, &mut rng)
+                })
+                .collect();
+            for rock in &rocks {
+                assert!(rock.speed.length() <= max_speed + 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_medium_asteroid_splits_in_two() {
+        let bounds = vec2(1000.0, 1000.0);
+        let parent = Asteroid::new_at(
+            vec2(500.0, 500.0),
+            Asteroid::ASTEROID_TAILLE / 2.0,
+            Vec2::ZERO,
+        );
+        let impact = Impact {
+            point: parent.position,
+            velocity: Vec2::ZERO,
+        };
+        let mut rng = SmallRng::seed_from_u64(4);
+
+        assert_eq!(parent.split(&impact, 4, 200.0, bounds, &mut rng).len(), 2);
+        let small = &parent.split(&impact, 4, 200.0, bounds, &mut rng)[0];
+        assert!(small.split(&impact, 4, 200.0, bounds, &mut rng).is_empty());
+    }
+}
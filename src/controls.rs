@@ -0,0 +1,561 @@
+//! Module `controls.rs`
+//!
+//! Ce module définit les actions du joueur (`Action`), leur état à chaque image (`ActionState`) et
+//! les touches qui les déclenchent (`KeyBindings`). Chaque action peut être associée à plusieurs
+//! touches, modifiables depuis les réglages et conservées dans un fichier texte du dossier de
+//! données du jeu. Les autres sources de commandes (manette...) produisent le même `ActionState`.
+
+use crate::storage;
+use crate::world::PlayerInput;
+use macroquad::input::{
+    is_key_down, is_key_pressed, is_mouse_button_down, is_mouse_button_pressed, mouse_position,
+    KeyCode, MouseButton,
+};
+use macroquad::math::Vec2;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// Nom du fichier des commandes, dans le dossier de données du jeu.
+const FILE_NAME: &str = "controls.cfg";
+
+/// Touches qui peuvent être associées à une action.
+const BINDABLE_KEYS: &[KeyCode] = &[
+    KeyCode::Space,
+    KeyCode::Apostrophe,
+    KeyCode::Comma,
+    KeyCode::Minus,
+    KeyCode::Period,
+    KeyCode::Slash,
+    KeyCode::Key0,
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Semicolon,
+    KeyCode::Equal,
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+    KeyCode::LeftBracket,
+    KeyCode::Backslash,
+    KeyCode::RightBracket,
+    KeyCode::GraveAccent,
+    KeyCode::Escape,
+    KeyCode::Enter,
+    KeyCode::Tab,
+    KeyCode::Backspace,
+    KeyCode::Insert,
+    KeyCode::Right,
+    KeyCode::Left,
+    KeyCode::Down,
+    KeyCode::Up,
+    KeyCode::PageUp,
+    KeyCode::PageDown,
+    KeyCode::Home,
+    KeyCode::End,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::Kp0,
+    KeyCode::Kp1,
+    KeyCode::Kp2,
+    KeyCode::Kp3,
+    KeyCode::Kp4,
+    KeyCode::Kp5,
+    KeyCode::Kp6,
+    KeyCode::Kp7,
+    KeyCode::Kp8,
+    KeyCode::Kp9,
+    KeyCode::KpDecimal,
+    KeyCode::KpDivide,
+    KeyCode::KpMultiply,
+    KeyCode::KpSubtract,
+    KeyCode::KpAdd,
+    KeyCode::KpEnter,
+    KeyCode::LeftShift,
+    KeyCode::LeftControl,
+    KeyCode::LeftAlt,
+    KeyCode::RightShift,
+    KeyCode::RightControl,
+    KeyCode::RightAlt,
+];
+
+/// Actions que le joueur peut déclencher pendant la partie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    RotateLeft,   // Rotation dans le sens inverse des aiguilles d'une montre
+    RotateRight,  // Rotation dans le sens des aiguilles d'une montre
+    Thrust,       // Poussée vers l'avant
+    Reverse,      // Rétro-poussée
+    Fire,         // Tir de l'arme sélectionnée
+    SwitchWeapon, // Passage à l'arme suivante
+    Hyperspace,   // Saut en hyperespace
+    ShieldBash,   // Onde de choc du bouclier
+    Pause,        // Mise en pause et reprise de la partie
+}
+
+impl Action {
+    /// Toutes les actions, dans l'ordre de l'écran des commandes.
+    pub const ALL: [Action; 9] = [
+        Action::RotateLeft,
+        Action::RotateRight,
+        Action::Thrust,
+        Action::Reverse,
+        Action::Fire,
+        Action::SwitchWeapon,
+        Action::Hyperspace,
+        Action::ShieldBash,
+        Action::Pause,
+    ];
+
+    /// Retourne le nom de l'action affiché dans les réglages.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::RotateLeft => "Tourner à gauche",
+            Action::RotateRight => "Tourner à droite",
+            Action::Thrust => "Accélérer",
+            Action::Reverse => "Freiner",
+            Action::Fire => "Tirer",
+            Action::SwitchWeapon => "Changer d'arme",
+            Action::Hyperspace => "Hyperespace",
+            Action::ShieldBash => "Onde de choc",
+            Action::Pause => "Pause",
+        }
+    }
+
+    /// Indique si l'action doit toujours garder au moins une touche : sans elles, il n'est plus
+    /// possible de jouer ni de quitter la partie.
+    pub fn is_essential(&self) -> bool {
+        matches!(self, Action::Thrust | Action::Fire | Action::Pause)
+    }
+
+    /// Retourne les touches associées par défaut à l'action.
+    fn default_keys(&self) -> &'static [KeyCode] {
+        match self {
+            Action::RotateLeft => &[KeyCode::Left],
+            Action::RotateRight => &[KeyCode::Right],
+            Action::Thrust => &[KeyCode::Up],
+            Action::Reverse => &[KeyCode::Down],
+            Action::Fire => &[KeyCode::Space],
+            Action::SwitchWeapon => &[KeyCode::Tab],
+            Action::Hyperspace => &[KeyCode::H],
+            Action::ShieldBash => &[KeyCode::B],
+            Action::Pause => &[KeyCode::Escape, KeyCode::P],
+        }
+    }
+}
+
+/// État des actions du joueur pendant une image, quelle que soit la source des commandes.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ActionState {
+    down: [bool; Action::ALL.len()], // Actions maintenues, dans l'ordre de `Action::ALL`
+    pressed: [bool; Action::ALL.len()], // Actions déclenchées pendant l'image
+    pub aim: Option<f32>,            // Cap visé, en radians
+    pub throttle: f32,               // Poussée analogique, entre 0 et 1
+}
+
+impl ActionState {
+    /// Ajoute l'état d'une action, sans effacer celui déjà donné par une autre commande.
+    ///
+    /// # Arguments
+    /// * `action` - L'action concernée.
+    /// * `down` - `true` si une commande de l'action est maintenue.
+    /// * `pressed` - `true` si une commande de l'action vient d'être déclenchée.
+    pub fn set(&mut self, action: Action, down: bool, pressed: bool) {
+        self.down[action as usize] |= down;
+        self.pressed[action as usize] |= pressed;
+    }
+
+    /// Indique si une commande de l'action est maintenue.
+    pub fn is_down(&self, action: Action) -> bool {
+        self.down[action as usize]
+    }
+
+    /// Indique si une commande de l'action vient d'être déclenchée.
+    pub fn is_pressed(&self, action: Action) -> bool {
+        self.pressed[action as usize]
+    }
+
+    /// Combine les états de deux sources de commandes utilisées en même temps.
+    pub fn merge(mut self, other: ActionState) -> ActionState {
+        for action in Action::ALL {
+            self.set(action, other.is_down(action), other.is_pressed(action));
+        }
+        self.aim = self.aim.or(other.aim);
+        self.throttle = self.throttle.max(other.throttle);
+        self
+    }
+
+    /// Retourne les commandes du joueur : les déplacements tant que leur commande est maintenue,
+    /// les actions ponctuelles au moment où leur commande est déclenchée.
+    pub fn player_input(&self) -> PlayerInput {
+        let mut input = PlayerInput {
+            turn_left: self.is_down(Action::RotateLeft),
+            turn_right: self.is_down(Action::RotateRight),
+            thrust: self.is_down(Action::Thrust),
+            reverse: self.is_down(Action::Reverse),
+            fire: self.is_pressed(Action::Fire),
+            fire_held: self.is_down(Action::Fire),
+            switch_weapon: self.is_pressed(Action::SwitchWeapon),
+            hyperspace: self.is_pressed(Action::Hyperspace),
+            shield_bash: self.is_pressed(Action::ShieldBash),
+            ..Default::default()
+        };
+        if let Some(aim) = self.aim {
+            input.set_aim(aim);
+        }
+        input.set_throttle(self.throttle);
+        input
+    }
+}
+
+/// Structure `KeyBindings` qui associe à chaque action les touches qui la déclenchent.
+///
+/// Une touche ne déclenche jamais plus d'une action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+    keys: [Vec<KeyCode>; Action::ALL.len()], // Touches de chaque action, dans l'ordre de `Action::ALL`
+}
+
+impl Default for KeyBindings {
+    /// Crée les commandes par défaut : flèches, espace, Tab, H, B, et Échap ou P pour la pause.
+    fn default() -> Self {
+        Self {
+            keys: Action::ALL.map(|action| action.default_keys().to_vec()),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Nombre maximal de touches par action.
+    pub const MAX_KEYS: usize = 3;
+
+    /// Crée des commandes où aucune action n'a de touche.
+    fn empty() -> Self {
+        Self {
+            keys: Default::default(),
+        }
+    }
+
+    /// Retourne les touches associées à une action.
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        &self.keys[action as usize]
+    }
+
+    /// Retourne l'action déclenchée par une touche, ou `None` si la touche est libre.
+    pub fn action_for(&self, key: KeyCode) -> Option<Action> {
+        Action::ALL
+            .into_iter()
+            .find(|&action| self.keys(action).contains(&key))
+    }
+
+    /// Associe une touche à une action. Si l'action a déjà `MAX_KEYS` touches, la plus ancienne
+    /// est remplacée.
+    ///
+    /// # Arguments
+    /// * `action` - L'action à déclencher.
+    /// * `key` - La nouvelle touche de l'action.
+    ///
+    /// # Retourne
+    /// * `Result<(), Action>` - L'action qui utilise déjà la touche en cas de conflit, auquel
+    ///   cas rien n'est modifié.
+    pub fn bind(&mut self, action: Action, key: KeyCode) -> Result<(), Action> {
+        match self.action_for(key) {
+            Some(other) if other == action => Ok(()),
+            Some(other) => Err(other),
+            None => {
+                let keys = &mut self.keys[action as usize];
+                if keys.len() >= Self::MAX_KEYS {
+                    keys.remove(0);
+                }
+                keys.push(key);
+                Ok(())
+            }
+        }
+    }
+
+    /// Retire toutes les touches d'une action.
+    ///
+    /// # Retourne
+    /// * `bool` - `false` si l'action est indispensable (voir `Action::is_essential`), auquel cas
+    ///   ses touches sont conservées.
+    pub fn clear(&mut self, action: Action) -> bool {
+        if action.is_essential() {
+            return false;
+        }
+        self.keys[action as usize].clear();
+        true
+    }
+
+    /// Lit l'état des actions au clavier pour l'image courante.
+    pub fn read_actions(&self) -> ActionState {
+        let mut state = ActionState::default();
+        for action in Action::ALL {
+            let keys = self.keys(action);
+            state.set(
+                action,
+                keys.iter().any(|&key| is_key_down(key)),
+                keys.iter().any(|&key| is_key_pressed(key)),
+            );
+        }
+        state
+    }
+
+    /// Retourne le chemin du fichier des commandes dans le dossier de données du jeu, ou `None`
+    /// si ce dossier est introuvable.
+    pub fn default_path() -> Option<PathBuf> {
+        storage::data_dir().map(|dir| dir.join(FILE_NAME))
+    }
+
+    /// Charge les commandes depuis un fichier, ou les commandes par défaut si le fichier est
+    /// absent ou illisible.
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        match fs::File::open(path) {
+            Ok(file) => Self::read_from(BufReader::new(file)),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Enregistre les commandes dans un fichier, en créant son dossier si besoin.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = fs::File::create(path)?;
+        self.write_to(&mut file)
+    }
+
+    /// Écrit les commandes au format texte, une action par ligne : `Action = Touche, Touche`.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            writer,
+            "# Commandes du jeu : action = touches séparées par des virgules"
+        )?;
+        for action in Action::ALL {
+            let keys: Vec<String> = self
+                .keys(action)
+                .iter()
+                .map(|key| format!("{:?}", key))
+                .collect();
+            writeln!(writer, "{:?} = {}", action, keys.join(", "))?;
+        }
+        Ok(())
+    }
+
+    /// Lit des commandes écrites par `write_to`.
+    ///
+    /// Les lignes, actions et touches inconnues sont ignorées, de même que les touches déjà prises
+    /// par une autre action. Les actions absentes du fichier gardent leurs touches par défaut,
+    /// si elles sont encore libres, et une action indispensable restée sans touche reprend les
+    /// siennes aux autres actions.
+    pub fn read_from(reader: impl BufRead) -> Self {
+        let mut bindings = Self::empty();
+        let mut listed = [false; Action::ALL.len()];
+        for line in reader.lines().map_while(Result::ok) {
+            let line = line.trim();
+            let Some((name, keys)) = line.split_once('=') else {
+                continue;
+            };
+            let Some(action) = Action::ALL
+                .into_iter()
+                .find(|action| format!("{:?}", action) == name.trim())
+            else {
+                continue;
+            };
+            listed[action as usize] = true;
+            for key in keys.split(',').filter_map(|key| parse_key(key.trim())) {
+                let _ = bindings.bind(action, key);
+            }
+        }
+        for action in Action::ALL.into_iter().filter(|&a| !listed[a as usize]) {
+            for &key in action.default_keys() {
+                let _ = bindings.bind(action, key);
+            }
+        }
+        for action in Action::ALL {
+            if action.is_essential() && bindings.keys(action).is_empty() {
+                for &key in action.default_keys() {
+                    for keys in bindings.keys.iter_mut() {
+                        keys.retain(|&other| other != key);
+                    }
+                    bindings.keys[action as usize].push(key);
+                }
+            }
+        }
+        bindings
+    }
+}
+
+/// Lit les actions à la souris pour l'image courante : le vaisseau vise le curseur, le clic gauche
+/// tire et le clic droit maintenu active la poussée.
+///
+/// # Arguments
+/// * `ship` - La position du vaisseau à l'écran.
+/// * `dead_zone` - Distance au vaisseau en dessous de laquelle le curseur ne donne pas de cap.
+pub fn read_mouse_actions(ship: Vec2, dead_zone: f32) -> ActionState {
+    let mut state = ActionState::default();
+    let to_cursor = Vec2::from(mouse_position()) - ship;
+    if to_cursor.length() > dead_zone {
+        state.aim = Some(to_cursor.to_angle());
+    }
+    state.set(
+        Action::Fire,
+        is_mouse_button_down(MouseButton::Left),
+        is_mouse_button_pressed(MouseButton::Left),
+    );
+    state.set(
+        Action::Thrust,
+        is_mouse_button_down(MouseButton::Right),
+        is_mouse_button_pressed(MouseButton::Right),
+    );
+    state
+}
+
+/// Retourne le nom affiché d'une touche.
+pub fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Space => "Espace".to_owned(),
+        KeyCode::Escape => "Échap".to_owned(),
+        KeyCode::Enter => "Entrée".to_owned(),
+        KeyCode::Left => "Gauche".to_owned(),
+        KeyCode::Right => "Droite".to_owned(),
+        KeyCode::Up => "Haut".to_owned(),
+        KeyCode::Down => "Bas".to_owned(),
+        _ => format!("{:?}", key),
+    }
+}
+
+/// Indique si une touche peut être associée à une action.
+pub fn is_bindable(key: KeyCode) -> bool {
+    BINDABLE_KEYS.contains(&key)
+}
+
+/// Retrouve une touche à partir de son nom dans le fichier des commandes.
+fn parse_key(name: &str) -> Option<KeyCode> {
+    BINDABLE_KEYS
+        .iter()
+        .copied()
+        .find(|key| format!("{:?}", key) == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bind_detects_conflicts() {
+        let mut bindings = KeyBindings::default();
+        assert_eq!(bindings.action_for(KeyCode::P), Some(Action::Pause));
+        assert_eq!(
+            bindings.bind(Action::Fire, KeyCode::Up),
+            Err(Action::Thrust)
+        );
+        assert_eq!(bindings.keys(Action::Fire), &[KeyCode::Space]);
+
+        assert_eq!(bindings.bind(Action::Fire, KeyCode::LeftControl), Ok(()));
+        assert_eq!(bindings.bind(Action::Fire, KeyCode::Space), Ok(()));
+        assert_eq!(bindings.keys(Action::Fire).len(), 2);
+        bindings.bind(Action::Fire, KeyCode::X).unwrap();
+        bindings.bind(Action::Fire, KeyCode::C).unwrap();
+        assert_eq!(
+            bindings.keys(Action::Fire),
+            &[KeyCode::LeftControl, KeyCode::X, KeyCode::C]
+        );
+
+        assert!(bindings.clear(Action::Reverse));
+        assert_eq!(bindings.action_for(KeyCode::Down), None);
+    }
+
+    #[test]
+    fn test_essential_actions_keep_a_key() {
+        let mut bindings = KeyBindings::default();
+        for action in [Action::Thrust, Action::Fire, Action::Pause] {
+            assert!(!bindings.clear(action));
+            assert_eq!(bindings.keys(action), action.default_keys());
+        }
+
+        // Un fichier qui prive la pause de ses touches la voit reprendre Échap et P.
+        let text = "Pause =\nShieldBash = Escape\n";
+        let bindings = KeyBindings::read_from(text.as_bytes());
+        assert_eq!(bindings.keys(Action::Pause), &[KeyCode::Escape, KeyCode::P]);
+        assert!(bindings.keys(Action::ShieldBash).is_empty());
+    }
+
+    #[test]
+    fn test_merged_sources_give_player_input() {
+        let mut keyboard = ActionState::default();
+        keyboard.set(Action::Thrust, true, false);
+        let mut gamepad = ActionState::default();
+        gamepad.set(Action::Fire, true, true);
+        gamepad.aim = Some(0.0);
+        gamepad.throttle = 0.5;
+
+        let state = keyboard.merge(gamepad);
+        assert!(state.is_down(Action::Thrust) && state.is_pressed(Action::Fire));
+        assert!(!state.is_pressed(Action::Thrust));
+        let input = state.player_input();
+        assert!(input.thrust && input.fire && input.fire_held && !input.turn_left);
+        assert_eq!((input.aim, input.throttle), (Some(0), 128));
+    }
+
+    #[test]
+    fn test_round_trip_and_tolerant_reading() {
+        let mut bindings = KeyBindings::default();
+        bindings.clear(Action::RotateLeft);
+        bindings.bind(Action::RotateLeft, KeyCode::Q).unwrap();
+        bindings.bind(Action::RotateLeft, KeyCode::Kp4).unwrap();
+        bindings.clear(Action::ShieldBash);
+        let mut text = Vec::new();
+        bindings.write_to(&mut text).unwrap();
+        assert_eq!(KeyBindings::read_from(text.as_slice()), bindings);
+
+        // Lignes inconnues ignorées, conflit refusé, action absente gardant sa touche par défaut.
+        let text = "n'importe quoi\nFire = Down, Inconnue, LeftControl\nJump = J\n";
+        let bindings = KeyBindings::read_from(text.as_bytes());
+        assert_eq!(
+            bindings.keys(Action::Fire),
+            &[KeyCode::Down, KeyCode::LeftControl]
+        );
+        assert!(bindings.keys(Action::Reverse).is_empty());
+        assert_eq!(bindings.keys(Action::Pause), &[KeyCode::Escape, KeyCode::P]);
+    }
+}
@@ -0,0 +1,793 @@
+//! Module `game.rs`
+//!
+//! Ce module définit la machine à états du jeu. Chaque écran (titre, partie, pause, fin de partie...)
+//! possède sa propre mise à jour et son propre affichage, ainsi que des actions exécutées à l'entrée
+//! et à la sortie de l'écran.
+
+use crate::controls::{self, Action, ActionState, KeyBindings};
+use crate::gamepad::Gamepads;
+use crate::gameplay::GameplayConfig;
+use crate::highscores::{HighScore, HighScores};
+use crate::render::{draw_background, draw_game, Textures};
+use crate::replay::Replay;
+use crate::stellarobject::StellarObject;
+use crate::ui::{draw_centered_text, menu, Button};
+use crate::wave::WaveConfig;
+use crate::world::{PlayerInput, World, WorldStatus, TIME_STEP};
+use macroquad::prelude::*;
+use std::path::PathBuf;
+
+/// Durée maximale d'une image prise en compte par la simulation, en secondes.
+///
+/// Évite que le jeu tente de rattraper un retard trop important (fenêtre déplacée, pause du système...).
+const MAX_FRAME_TIME: f32 = 0.25;
+
+/// Durée d'affichage de la bannière de vague, en secondes.
+const LEVEL_TRANSITION_DURATION: f32 = 3.0;
+
+/// Les différents écrans du jeu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameState {
+    Title,           // Écran de démarrage
+    Playing,         // Partie en cours
+    Paused,          // Partie en pause
+    LevelTransition, // Bannière affichée au début de chaque vague
+    GameOver,        // Écran de fin de partie
+    NameEntry,       // Saisie du nom pour un score qui entre au tableau des meilleurs scores
+    Settings,        // Réglages
+    Controls,        // Réglage des touches
+    HighScores,      // Tableau des meilleurs scores
+}
+
+/// Changement d'écran demandé par la mise à jour de l'écran courant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    Stay,              // Rester sur l'écran courant
+    Switch(GameState), // Passer à un autre écran
+    Quit,              // Quitter le jeu
+}
+
+/// Options de lancement passées en ligne de commande.
+pub struct Options {
+    pub seed: Option<u64>,           // Graine imposée (`--seed`)
+    pub record_path: Option<String>, // Fichier où enregistrer le replay des parties (`--record`)
+    pub playback: Option<Replay>,    // Replay à rejouer (`--replay`)
+}
+
+/// Structure `Game` qui regroupe l'état de l'application et l'écran courant.
+pub struct Game {
+    state: GameState,                  // Écran courant
+    options: Options,                  // Options de lancement
+    textures: Textures,                // Textures du jeu
+    waves: WaveConfig,                 // Progression des vagues des prochaines parties
+    gameplay: GameplayConfig,          // Réglages de jeu des prochaines parties
+    recording: Replay,                 // Enregistrement de la partie en cours
+    world: World,                      // Monde de la partie en cours
+    accumulator: f32,                  // Temps écoulé pas encore simulé
+    pending: PlayerInput, // Commandes ponctuelles (tir, arme, hyperespace, onde de choc) pas encore simulées
+    transition_time: f32, // Temps passé sur la bannière de vague
+    high_scores: HighScores, // Tableau des meilleurs scores
+    high_scores_path: Option<PathBuf>, // Fichier du tableau des meilleurs scores
+    player_name: String,  // Nom saisi pour le tableau des meilleurs scores
+    fullscreen: bool,     // Mode plein écran activé
+    mouse_aim: bool,      // Visée à la souris : le vaisseau tourne vers le curseur
+    bindings: KeyBindings, // Touches associées aux actions du joueur
+    gamepads: Gamepads,   // Manettes de jeu branchées
+    actions: ActionState, // Actions du joueur au clavier et à la manette pendant l'image
+    bindings_path: Option<PathBuf>, // Fichier des commandes
+    rebinding: Option<Action>, // Action dont on attend la nouvelle touche sur l'écran des commandes
+    controls_message: String, // Message affiché sur l'écran des commandes
+    settings_origin: GameState, // Écran vers lequel revenir en quittant les réglages
+}
+
+impl Game {
+    /// Crée le jeu sur l'écran de démarrage.
+    ///
+    /// # Arguments
+    /// * `options` - Options de lancement.
+    /// * `textures` - Textures du jeu.
+    pub fn new(options: Options, textures: Textures) -> Self {
+        let waves = WaveConfig::default();
+        let gameplay = GameplayConfig::default();
+        let recording = Self::new_recording(&options, waves, gameplay);
+        let world = recording.world();
+        let high_scores_path = HighScores::default_path();
+        let high_scores = high_scores_path
+            .as_ref()
+            .map(HighScores::load)
+            .unwrap_or_default();
+        let bindings_path = KeyBindings::default_path();
+        let bindings = bindings_path
+            .as_ref()
+            .map(KeyBindings::load)
+            .unwrap_or_default();
+        Self {
+            state: GameState::Title,
+            options,
+            textures,
+            waves,
+            gameplay,
+            recording,
+            world,
+            accumulator: 0.0,
+            pending: PlayerInput::default(),
+            transition_time: 0.0,
+            high_scores,
+            high_scores_path,
+            player_name: String::new(),
+            fullscreen: true,
+            mouse_aim: false,
+            bindings,
+            gamepads: Gamepads::new(),
+            actions: ActionState::default(),
+            bindings_path,
+            rebinding: None,
+            controls_message: String::new(),
+            settings_origin: GameState::Title,
+        }
+    }
+
+    /// Met à jour l'écran courant et change d'écran si nécessaire.
+    ///
+    /// # Retourne
+    /// * un booléen - `false` si le joueur a quitté le jeu, `true` sinon.
+    pub fn update(&mut self) -> bool {
+        self.actions = self
+            .bindings
+            .read_actions()
+            .merge(self.gamepads.read_actions());
+        // La souris ne pilote le vaisseau que pendant la partie, pour laisser les clics aux menus.
+        if self.mouse_aim && self.state == GameState::Playing {
+            let spaceship = self.world.spaceship();
+            self.actions = self.actions.merge(controls::read_mouse_actions(
+                spaceship.position(),
+                spaceship.radius(),
+            ));
+        }
+        let transition = match self.state {
+            GameState::Title => self.update_title(),
+            GameState::Playing => self.update_playing(),
+            GameState::Paused => self.update_paused(),
+            GameState::LevelTransition => self.update_level_transition(),
+            GameState::GameOver => self.update_game_over(),
+            GameState::NameEntry => self.update_name_entry(),
+            GameState::Settings => self.update_settings(),
+            GameState::Controls => self.update_controls(),
+            GameState::HighScores => self.update_high_scores(),
+        };
+
+        match transition {
+            Transition::Stay => true,
+            Transition::Switch(next) => {
+                self.exit(self.state);
+                self.state = next;
+                self.enter(next);
+                true
+            }
+            Transition::Quit => {
+                self.exit(self.state);
+                false
+            }
+        }
+    }
+
+    /// Dessine l'écran courant.
+    pub fn draw(&self) {
+        match self.state {
+            GameState::Title => self.draw_title(),
+            GameState::Playing => self.draw_playing(),
+            GameState::Paused => self.draw_paused(),
+            GameState::LevelTransition => self.draw_level_transition(),
+            GameState::GameOver => self.draw_game_over(),
+            GameState::NameEntry => self.draw_name_entry(),
+            GameState::Settings => self.draw_settings(),
+            GameState::Controls => self.draw_controls(),
+            GameState::HighScores => self.draw_high_scores(),
+        }
+    }
+
+    /// Actions exécutées à l'entrée dans un écran.
+    fn enter(&mut self, state: GameState) {
+        match state {
+            GameState::Playing => {
+                self.accumulator = 0.0;
+                self.pending = PlayerInput::default();
+            }
+            GameState::LevelTransition => self.transition_time = 0.0,
+            GameState::Controls => {
+                self.rebinding = None;
+                self.controls_message.clear();
+            }
+            GameState::NameEntry => while get_char_pressed().is_some() {}, // Oublie les touches de la partie
+            _ => {}
+        }
+    }
+
+    /// Actions exécutées à la sortie d'un écran.
+    fn exit(&mut self, state: GameState) {
+        if state == GameState::Playing {
+            self.save_recording();
+        }
+    }
+
+    /// Prépare l'enregistrement d'une nouvelle partie.
+    ///
+    /// En mode replay, la partie reprend les paramètres du replay rejoué ; sinon la graine imposée
+    /// en ligne de commande est utilisée, ou une graine aléatoire à défaut.
+    fn new_recording(options: &Options, waves: WaveConfig, gameplay: GameplayConfig) -> Replay {
+        match &options.playback {
+            Some(replay) => replay.restart(),
+            None => Replay::new(
+                screen_width(),
+                screen_height(),
+                waves,
+                gameplay,
+                options.seed.unwrap_or_else(::rand::random),
+            ),
+        }
+    }
+
+    /// Démarre une nouvelle partie, à partir de la première vague.
+    fn start_game(&mut self) {
+        self.recording = Self::new_recording(&self.options, self.waves, self.gameplay);
+        self.world = self.recording.world();
+    }
+
+    /// Enregistre le replay de la partie si l'option `--record <fichier>` a été donnée.
+    fn save_recording(&self) {
+        if let Some(path) = &self.options.record_path {
+            if let Err(error) = self.recording.save(path) {
+                eprintln!("Impossible d'enregistrer le replay : {}", error);
+            }
+        }
+    }
+
+    /// Ajoute le score de la partie terminée au tableau des meilleurs scores, puis enregistre le
+    /// tableau dans le dossier de données de l'utilisateur.
+    fn record_score(&mut self) {
+        let name = self.player_name.trim();
+        self.high_scores.insert(HighScore {
+            name: if name.is_empty() { "???" } else { name }.to_owned(),
+            score: self.world.score(),
+            level: self.world.level(),
+        });
+        if let Some(path) = &self.high_scores_path {
+            if let Err(error) = self.high_scores.save(path) {
+                eprintln!("Impossible d'enregistrer les meilleurs scores : {}", error);
+            }
+        }
+    }
+
+    /// Écran qui suit la défaite : la saisie du nom si le score entre au tableau des meilleurs
+    /// scores, l'écran de fin de partie sinon. Les parties rejouées ne sont pas classées.
+    fn after_defeat(&self) -> GameState {
+        if self.options.playback.is_none() && self.high_scores.qualifies(self.world.score()) {
+            GameState::NameEntry
+        } else {
+            GameState::GameOver
+        }
+    }
+
+    /// Fraction du pas de simulation écoulée, utilisée pour interpoler l'affichage.
+    fn alpha(&self) -> f32 {
+        self.accumulator / TIME_STEP
+    }
+
+    // --- Écran de démarrage ---
+
+    /// Boutons de l'écran de démarrage.
+    fn title_menu() -> Vec<Button> {
+        menu(
+            screen_height() / 2.0 + 150.0,
+            &[
+                ("Jouer", GREEN),
+                ("Meilleurs scores", SKYBLUE),
+                ("Réglages", LIGHTGRAY),
+                ("Quitter", RED),
+            ],
+        )
+    }
+
+    /// Mise à jour de l'écran de démarrage.
+    fn update_title(&mut self) -> Transition {
+        let buttons = Self::title_menu();
+        if buttons[0].is_clicked() {
+            self.start_game();
+            Transition::Switch(GameState::LevelTransition)
+        } else if buttons[1].is_clicked() {
+            Transition::Switch(GameState::HighScores)
+        } else if buttons[2].is_clicked() {
+            self.settings_origin = GameState::Title;
+            Transition::Switch(GameState::Settings)
+        } else if buttons[3].is_clicked() {
+            Transition::Quit
+        } else {
+            Transition::Stay
+        }
+    }
+
+    /// Affichage de l'écran de démarrage.
+    fn draw_title(&self) {
+        draw_background(&self.textures.background, 0.5);
+        draw_centered_text(
+            "Mini-projet Asteroids",
+            screen_height() / 2.0 - 100.0,
+            150.0,
+            WHITE,
+        );
+        draw_centered_text(
+            "Réalisé par LALMASSI Ilyan & BRULU Thomas",
+            screen_height() / 2.0 + 50.0,
+            50.0,
+            WHITE,
+        );
+        for button in Self::title_menu() {
+            button.draw();
+        }
+    }
+
+    // --- Partie en cours ---
+
+    /// Mise à jour de la partie en cours.
+    fn update_playing(&mut self) -> Transition {
+        if self.actions.is_pressed(Action::Pause) {
+            return Transition::Switch(GameState::Paused);
+        }
+
+        // Avance le monde par pas fixes, quel que soit le nombre d'images par seconde.
+        let mut input = self.actions.player_input();
+        self.pending.fire |= input.fire;
+        self.pending.switch_weapon |= input.switch_weapon;
+        self.pending.hyperspace |= input.hyperspace;
+        self.pending.shield_bash |= input.shield_bash;
+        self.accumulator += get_frame_time().min(MAX_FRAME_TIME);
+        while self.accumulator >= TIME_STEP {
+            self.accumulator -= TIME_STEP;
+            let step_input = match &self.options.playback {
+                // Commandes lues dans le replay, jusqu'à épuisement.
+                Some(replay) => match replay.inputs().get(self.recording.inputs().len()) {
+                    Some(step_input) => *step_input,
+                    None => return Transition::Switch(GameState::GameOver), // Fin du replay
+                },
+                None => {
+                    // Une seule action ponctuelle par appui
+                    input.fire = self.pending.fire;
+                    input.switch_weapon = self.pending.switch_weapon;
+                    input.hyperspace = self.pending.hyperspace;
+                    input.shield_bash = self.pending.shield_bash;
+                    self.pending = PlayerInput::default();
+                    input
+                }
+            };
+
+            self.recording.record(step_input);
+            match self.world.step(&step_input) {
+                WorldStatus::Running => {}
+                WorldStatus::WaveCleared => return Transition::Switch(GameState::LevelTransition),
+                WorldStatus::Defeat => return Transition::Switch(self.after_defeat()),
+            }
+        }
+        Transition::Stay
+    }
+
+    /// Affichage de la partie en cours.
+    fn draw_playing(&self) {
+        draw_game(&self.world, &self.textures, self.alpha());
+    }
+
+    // --- Pause ---
+
+    /// Boutons du menu de pause.
+    fn pause_menu() -> Vec<Button> {
+        menu(
+            screen_height() / 2.0 - 50.0,
+            &[
+                ("Reprendre", GREEN),
+                ("Recommencer", SKYBLUE),
+                ("Réglages", LIGHTGRAY),
+                ("Menu principal", RED),
+            ],
+        )
+    }
+
+    /// Mise à jour de la pause.
+    ///
+    /// Le monde n'est pas mis à jour : les objets, le score et l'invincibilité du vaisseau sont figés.
+    fn update_paused(&mut self) -> Transition {
+        let buttons = Self::pause_menu();
+        if buttons[0].is_clicked() || self.actions.is_pressed(Action::Pause) {
+            Transition::Switch(GameState::Playing)
+        } else if buttons[1].is_clicked() {
+            self.start_game();
+            Transition::Switch(GameState::LevelTransition)
+        } else if buttons[2].is_clicked() {
+            self.settings_origin = GameState::Paused;
+            Transition::Switch(GameState::Settings)
+        } else if buttons[3].is_clicked() {
+            Transition::Switch(GameState::Title)
+        } else {
+            Transition::Stay
+        }
+    }
+
+    /// Affichage de la pause.
+    fn draw_paused(&self) {
+        draw_game(&self.world, &self.textures, self.alpha());
+        draw_rectangle(
+            0.0,
+            0.0,
+            screen_width(),
+            screen_height(),
+            Color::new(0.0, 0.0, 0.0, 0.5),
+        );
+        draw_centered_text("Pause", screen_height() / 2.0 - 120.0, 150.0, WHITE);
+        for button in Self::pause_menu() {
+            button.draw();
+        }
+    }
+
+    // --- Bannière de vague ---
+
+    /// Mise à jour de la bannière de vague.
+    ///
+    /// Le monde reste figé pendant l'affichage de la bannière, puis la partie reprend.
+    fn update_level_transition(&mut self) -> Transition {
+        self.transition_time += get_frame_time();
+        if self.transition_time >= LEVEL_TRANSITION_DURATION {
+            Transition::Switch(GameState::Playing)
+        } else {
+            Transition::Stay
+        }
+    }
+
+    /// Affichage de la bannière de vague, précédée du bilan de la vague terminée.
+    fn draw_level_transition(&self) {
+        draw_game(&self.world, &self.textures, self.alpha());
+        draw_centered_text(
+            &format!("Vague {}", self.world.level()),
+            screen_height() / 2.0,
+            150.0,
+            GOLD,
+        );
+
+        let Some(summary) = self.world.last_wave() else {
+            return;
+        };
+        let lines = [
+            format!("Destructions : {}", summary.destruction_points),
+            format!("Meilleur combo : {}", summary.best_combo),
+            format!(
+                "Précision : {:.0} % ({} / {})",
+                summary.accuracy() * 100.0,
+                summary.hits,
+                summary.shots
+            ),
+            format!("Bonus de précision : {}", summary.accuracy_bonus),
+            format!("Bonus de fin de vague : {}", summary.clear_bonus),
+            format!("Total de la vague {} : {}", summary.level, summary.total()),
+        ];
+        for (index, line) in lines.iter().enumerate() {
+            draw_centered_text(
+                line,
+                screen_height() / 2.0 + 100.0 + index as f32 * 45.0,
+                40.0,
+                WHITE,
+            );
+        }
+    }
+
+    // --- Fin de partie ---
+
+    /// Boutons de l'écran de fin de partie.
+    fn game_over_menu() -> Vec<Button> {
+        menu(
+            screen_height() / 2.0 + 100.0,
+            &[
+                ("Rejouer", GREEN),
+                ("Menu principal", SKYBLUE),
+                ("Quitter", RED),
+            ],
+        )
+    }
+
+    /// Mise à jour de l'écran de fin de partie.
+    fn update_game_over(&mut self) -> Transition {
+        let buttons = Self::game_over_menu();
+        if buttons[0].is_clicked() {
+            self.start_game();
+            Transition::Switch(GameState::LevelTransition)
+        } else if buttons[1].is_clicked() {
+            Transition::Switch(GameState::Title)
+        } else if buttons[2].is_clicked() {
+            Transition::Quit
+        } else {
+            Transition::Stay
+        }
+    }
+
+    /// Affichage de l'écran de fin de partie.
+    fn draw_game_over(&self) {
+        draw_background(&self.textures.background, 0.5);
+        draw_centered_text("Game Over!", screen_height() / 2.0 - 150.0, 300.0, RED);
+        draw_centered_text(
+            &format!(
+                "Score final : {} - Vague {}",
+                self.world.score(),
+                self.world.level()
+            ),
+            screen_height() / 2.0,
+            50.0,
+            WHITE,
+        );
+        draw_centered_text(
+            &format!("Graine : {}", self.world.seed()),
+            screen_height() / 2.0 + 50.0,
+            30.0,
+            GRAY,
+        );
+        for button in Self::game_over_menu() {
+            button.draw();
+        }
+    }
+
+    // --- Saisie du nom ---
+
+    /// Boutons de l'écran de saisie du nom.
+    fn name_entry_menu() -> Vec<Button> {
+        menu(screen_height() / 2.0 + 150.0, &[("Valider", GREEN)])
+    }
+
+    /// Mise à jour de l'écran de saisie du nom.
+    ///
+    /// Le nom est saisi au clavier ; Entrée ou le bouton valident le score, Échap l'abandonne.
+    fn update_name_entry(&mut self) -> Transition {
+        while let Some(character) = get_char_pressed() {
+            if !character.is_control()
+                && self.player_name.chars().count() < HighScores::MAX_NAME_LENGTH
+            {
+                self.player_name.push(character);
+            }
+        }
+        if is_key_pressed(KeyCode::Backspace) {
+            self.player_name.pop();
+        }
+
+        if is_key_pressed(KeyCode::Enter) || Self::name_entry_menu()[0].is_clicked() {
+            self.record_score();
+            Transition::Switch(GameState::GameOver)
+        } else if is_key_pressed(KeyCode::Escape) {
+            Transition::Switch(GameState::GameOver)
+        } else {
+            Transition::Stay
+        }
+    }
+
+    /// Affichage de l'écran de saisie du nom.
+    fn draw_name_entry(&self) {
+        draw_background(&self.textures.background, 0.5);
+        draw_centered_text(
+            "Nouveau record !",
+            screen_height() / 2.0 - 150.0,
+            120.0,
+            GOLD,
+        );
+        draw_centered_text(
+            &format!("Score : {}", self.world.score()),
+            screen_height() / 2.0 - 50.0,
+            50.0,
+            WHITE,
+        );
+        draw_centered_text(
+            &format!("Votre nom : {}_", self.player_name),
+            screen_height() / 2.0 + 50.0,
+            60.0,
+            SKYBLUE,
+        );
+        for button in Self::name_entry_menu() {
+            button.draw();
+        }
+    }
+
+    // --- Réglages ---
+
+    /// Boutons de l'écran des réglages.
+    fn settings_menu(&self) -> Vec<Button> {
+        let yes_no = |enabled| if enabled { "Oui" } else { "Non" };
+        menu(
+            screen_height() / 2.0 - 50.0,
+            &[
+                (
+                    &format!("Plein écran : {}", yes_no(self.fullscreen)),
+                    LIGHTGRAY,
+                ),
+                (
+                    &format!("Visée souris : {}", yes_no(self.mouse_aim)),
+                    LIGHTGRAY,
+                ),
+                ("Commandes", LIGHTGRAY),
+                ("Retour", GREEN),
+            ],
+        )
+    }
+
+    /// Mise à jour de l'écran des réglages.
+    ///
+    /// La visée à la souris fait tourner le vaisseau vers le curseur : le clic gauche tire et le
+    /// clic droit pousse, en plus des touches du clavier.
+    fn update_settings(&mut self) -> Transition {
+        let buttons = self.settings_menu();
+        if buttons[0].is_clicked() {
+            self.fullscreen = !self.fullscreen;
+            set_fullscreen(self.fullscreen);
+            Transition::Stay
+        } else if buttons[1].is_clicked() {
+            self.mouse_aim = !self.mouse_aim;
+            Transition::Stay
+        } else if buttons[2].is_clicked() {
+            Transition::Switch(GameState::Controls)
+        } else if buttons[3].is_clicked() || is_key_pressed(KeyCode::Escape) {
+            Transition::Switch(self.settings_origin)
+        } else {
+            Transition::Stay
+        }
+    }
+
+    /// Affichage de l'écran des réglages.
+    fn draw_settings(&self) {
+        draw_background(&self.textures.background, 0.5);
+        draw_centered_text("Réglages", screen_height() / 2.0 - 150.0, 100.0, WHITE);
+        for button in self.settings_menu() {
+            button.draw();
+        }
+    }
+
+    // --- Commandes ---
+
+    /// Boutons de l'écran des commandes : une ligne par action, puis la réinitialisation et le
+    /// retour aux réglages.
+    fn controls_menu(&self) -> Vec<Button> {
+        let (width, height, spacing) = (800.0, 55.0, 65.0);
+        let x = screen_width() / 2.0 - width / 2.0;
+        let rows = Action::ALL.iter().map(|&action| {
+            let keys: Vec<String> = self
+                .bindings
+                .keys(action)
+                .iter()
+                .map(|&key| controls::key_name(key))
+                .collect();
+            let (keys, color) = if self.rebinding == Some(action) {
+                ("...".to_owned(), GOLD)
+            } else if keys.is_empty() {
+                ("aucune".to_owned(), GRAY)
+            } else {
+                (keys.join(", "), LIGHTGRAY)
+            };
+            (format!("{} : {}", action.name(), keys), color)
+        });
+        rows.chain([
+            ("Réinitialiser".to_owned(), SKYBLUE),
+            ("Retour".to_owned(), GREEN),
+        ])
+        .enumerate()
+        .map(|(index, (label, color))| {
+            let y = 180.0 + index as f32 * spacing;
+            Button::new(label, Rect::new(x, y, width, height), color)
+        })
+        .collect()
+    }
+
+    /// Enregistre les commandes dans le dossier de données de l'utilisateur.
+    fn save_bindings(&self) {
+        if let Some(path) = &self.bindings_path {
+            if let Err(error) = self.bindings.save(path) {
+                eprintln!("Impossible d'enregistrer les commandes : {}", error);
+            }
+        }
+    }
+
+    /// Mise à jour de l'écran des commandes.
+    ///
+    /// Un clic sur une action attend la touche à lui ajouter : Suppr retire toutes ses touches,
+    /// sauf pour les actions indispensables, Échap annule. Une touche déjà utilisée par une autre
+    /// action est refusée.
+    fn update_controls(&mut self) -> Transition {
+        if let Some(action) = self.rebinding {
+            let Some(key) = get_last_key_pressed() else {
+                return Transition::Stay;
+            };
+            self.rebinding = None;
+            self.controls_message = match key {
+                KeyCode::Escape => String::new(),
+                KeyCode::Delete if self.bindings.clear(action) => {
+                    format!("Touches de « {} » retirées", action.name())
+                }
+                KeyCode::Delete => format!("« {} » doit garder une touche", action.name()),
+                key if !controls::is_bindable(key) => "Touche non prise en charge".to_owned(),
+                key => match self.bindings.bind(action, key) {
+                    Ok(()) => String::new(),
+                    Err(other) => format!(
+                        "{} est déjà utilisée par « {} »",
+                        controls::key_name(key),
+                        other.name()
+                    ),
+                },
+            };
+            self.save_bindings();
+            return Transition::Stay;
+        }
+
+        let buttons = self.controls_menu();
+        if let Some(index) = buttons[..Action::ALL.len()]
+            .iter()
+            .position(Button::is_clicked)
+        {
+            self.rebinding = Some(Action::ALL[index]);
+            self.controls_message = format!(
+                "Appuyez sur une touche pour « {} » (Suppr : retirer, Échap : annuler)",
+                Action::ALL[index].name()
+            );
+            Transition::Stay
+        } else if buttons[Action::ALL.len()].is_clicked() {
+            self.bindings = KeyBindings::default();
+            self.controls_message = "Commandes par défaut rétablies".to_owned();
+            self.save_bindings();
+            Transition::Stay
+        } else if buttons[Action::ALL.len() + 1].is_clicked() || is_key_pressed(KeyCode::Escape) {
+            Transition::Switch(GameState::Settings)
+        } else {
+            Transition::Stay
+        }
+    }
+
+    /// Affichage de l'écran des commandes.
+    fn draw_controls(&self) {
+        draw_background(&self.textures.background, 0.5);
+        draw_centered_text("Commandes", 100.0, 100.0, WHITE);
+        draw_centered_text(&self.controls_message, 155.0, 35.0, YELLOW);
+        for button in self.controls_menu() {
+            button.draw();
+        }
+    }
+
+    // --- Meilleurs scores ---
+
+    /// Boutons de l'écran des meilleurs scores.
+    fn high_scores_menu() -> Vec<Button> {
+        menu(screen_height() - 150.0, &[("Retour", GREEN)])
+    }
+
+    /// Mise à jour de l'écran des meilleurs scores.
+    fn update_high_scores(&mut self) -> Transition {
+        if Self::high_scores_menu()[0].is_clicked() || is_key_pressed(KeyCode::Escape) {
+            Transition::Switch(GameState::Title)
+        } else {
+            Transition::Stay
+        }
+    }
+
+    /// Affichage de l'écran des meilleurs scores.
+    fn draw_high_scores(&self) {
+        draw_background(&self.textures.background, 0.5);
+        draw_centered_text("Meilleurs scores", 150.0, 100.0, WHITE);
+        if self.high_scores.entries().is_empty() {
+            draw_centered_text("Aucun score pour le moment", 300.0, 50.0, GRAY);
+        }
+        for (rank, entry) in self.high_scores.entries().iter().enumerate() {
+            draw_centered_text(
+                &format!(
+                    "{:>2}. {} - {} (vague {})",
+                    rank + 1,
+                    entry.name,
+                    entry.score,
+                    entry.level
+                ),
+                250.0 + rank as f32 * 55.0,
+                50.0,
+                WHITE,
+            );
+        }
+        for button in Self::high_scores_menu() {
+            button.draw();
+        }
+    }
+}
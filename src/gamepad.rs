@@ -0,0 +1,153 @@
+//! Module `gamepad.rs`
+//!
+//! Ce module lit les manettes de jeu et traduit leurs commandes en actions du joueur, comme le
+//! clavier : les deux peuvent être utilisés en même temps. Le stick gauche donne le cap du
+//! vaisseau, la gâchette droite dose la poussée et les boutons déclenchent les autres actions.
+//!
+//! La lecture des manettes repose sur la bibliothèque `gilrs` et n'est compilée qu'avec la
+//! fonctionnalité `gamepad` (`cargo run --features gamepad`), qui demande `libudev` sous Linux.
+//! Sans elle, aucune manette n'est lue.
+
+use crate::controls::{Action, ActionState};
+#[cfg(feature = "gamepad")]
+use gilrs::{Axis, Button, EventType, Gilrs};
+use macroquad::math::vec2;
+
+/// Inclinaison minimale du stick prise en compte, pour ignorer son jeu au repos.
+const STICK_DEAD_ZONE: f32 = 0.3;
+
+/// Enfoncement de la gâchette gauche au-delà duquel elle déclenche la rétro-poussée.
+const TRIGGER_THRESHOLD: f32 = 0.5;
+
+/// Boutons de la manette et actions qu'ils déclenchent.
+#[cfg(feature = "gamepad")]
+const BUTTONS: [(Button, Action); 10] = [
+    (Button::DPadLeft, Action::RotateLeft),
+    (Button::DPadRight, Action::RotateRight),
+    (Button::DPadUp, Action::Thrust),
+    (Button::DPadDown, Action::Reverse),
+    (Button::South, Action::Fire),
+    (Button::RightTrigger, Action::Fire),
+    (Button::North, Action::SwitchWeapon),
+    (Button::West, Action::Hyperspace),
+    (Button::East, Action::ShieldBash),
+    (Button::Start, Action::Pause),
+];
+
+/// Structure `Gamepads` qui donne accès aux manettes branchées.
+pub struct Gamepads {
+    #[cfg(feature = "gamepad")]
+    gilrs: Option<Gilrs>, // Accès aux manettes, absent si le système ne permet pas de les lire
+}
+
+impl Gamepads {
+    /// Prépare la lecture des manettes. Une erreur d'initialisation est signalée sur la sortie
+    /// d'erreur, et le jeu continue au clavier.
+    pub fn new() -> Self {
+        #[cfg(feature = "gamepad")]
+        {
+            let gilrs = Gilrs::new()
+                .map_err(|error| eprintln!("Impossible de lire les manettes : {}", error))
+                .ok();
+            Self { gilrs }
+        }
+        #[cfg(not(feature = "gamepad"))]
+        Self {}
+    }
+
+    /// Lit l'état des actions sur toutes les manettes branchées pour l'image courante.
+    #[cfg(feature = "gamepad")]
+    pub fn read_actions(&mut self) -> ActionState {
+        let mut state = ActionState::default();
+        let Some(gilrs) = &mut self.gilrs else {
+            return state;
+        };
+
+        // Boutons pressés depuis l'image précédente
+        while let Some(event) = gilrs.next_event() {
+            if let EventType::ButtonPressed(button, _) = event.event {
+                for &(_, action) in BUTTONS.iter().filter(|(mapped, _)| *mapped == button) {
+                    state.set(action, false, true);
+                }
+            }
+        }
+
+        for (_, gamepad) in gilrs.gamepads() {
+            for (button, action) in BUTTONS {
+                state.set(action, gamepad.is_pressed(button), false);
+            }
+
+            let trigger = |button| gamepad.button_data(button).map_or(0.0, |data| data.value());
+            apply_analog(
+                &mut state,
+                gamepad.value(Axis::LeftStickX),
+                gamepad.value(Axis::LeftStickY),
+                trigger(Button::RightTrigger2),
+                trigger(Button::LeftTrigger2),
+            );
+        }
+        state
+    }
+
+    /// Sans la fonctionnalité `gamepad`, aucune manette n'est lue.
+    #[cfg(not(feature = "gamepad"))]
+    pub fn read_actions(&mut self) -> ActionState {
+        ActionState::default()
+    }
+}
+
+/// Ajoute à l'état des actions les commandes analogiques d'une manette : le stick donne le cap visé
+/// s'il est assez incliné, la gâchette droite la poussée et la gâchette gauche la rétro-poussée.
+/// Une manette déjà lue garde la priorité pour le cap, et la poussée la plus forte l'emporte.
+///
+/// # Arguments
+/// * `state` - L'état des actions de l'image, éventuellement déjà rempli par une autre manette.
+/// * `stick_x` - Inclinaison horizontale du stick, de -1 (gauche) à 1 (droite).
+/// * `stick_y` - Inclinaison verticale du stick, de -1 (bas) à 1 (haut).
+/// * `right_trigger` - Enfoncement de la gâchette droite, de 0 à 1.
+/// * `left_trigger` - Enfoncement de la gâchette gauche, de 0 à 1.
+#[cfg_attr(not(feature = "gamepad"), allow(dead_code))]
+fn apply_analog(
+    state: &mut ActionState,
+    stick_x: f32,
+    stick_y: f32,
+    right_trigger: f32,
+    left_trigger: f32,
+) {
+    // L'axe vertical du stick est orienté vers le haut, celui de l'écran vers le bas.
+    let stick = vec2(stick_x, -stick_y);
+    if stick.length() > STICK_DEAD_ZONE {
+        state.aim = state.aim.or(Some(stick.to_angle()));
+    }
+    state.throttle = state.throttle.max(right_trigger);
+    if left_trigger > TRIGGER_THRESHOLD {
+        state.set(Action::Reverse, true, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_stick_and_triggers_give_player_input() {
+        // Stick au repos et gâchettes relâchées : aucune commande.
+        let mut state = ActionState::default();
+        apply_analog(&mut state, 0.1, -0.2, 0.0, 0.2);
+        assert_eq!(state.player_input(), ActionState::default().player_input());
+
+        // Stick vers le haut de l'écran, gâchettes enfoncées.
+        apply_analog(&mut state, 0.0, 1.0, 0.5, 0.8);
+        assert!((state.aim.unwrap() + FRAC_PI_2).abs() < 1e-6);
+        assert!(state.is_down(Action::Reverse));
+        let input = state.player_input();
+        assert!(input.reverse && input.aim.is_some());
+        assert_eq!(input.throttle, 128);
+
+        // Une seconde manette ne change pas le cap, mais peut pousser plus fort.
+        apply_analog(&mut state, 1.0, 0.0, 1.0, 0.0);
+        assert!((state.aim.unwrap() + FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(state.player_input().throttle, 255);
+    }
+}
@@ -0,0 +1,212 @@
+//! Module `gameplay.rs`
+//!
+//! Ce module définit les réglages de jeu qui restent les mêmes d'une vague à l'autre (armes,
+//! bonus, hyperespace, vies et bouclier du vaisseau...), par opposition à la progression des
+//! vagues décrite dans `wave.rs`.
+
+use std::ops::RangeInclusive;
+
+/// Structure `GameplayConfig` qui regroupe les réglages de jeu communs à toutes les vagues.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameplayConfig {
+    pub well_strength: f32, // Intensité de l'attraction des puits, en pixels cubes par seconde au carré
+    pub well_horizon: f32,  // Rayon de l'horizon des puits
+    pub missile_lifetime: f32, // Durée de vie d'un missile, en secondes : sa portée vaut cette durée multipliée par sa vitesse
+    pub max_missiles: usize,   // Nombre maximal de missiles en vol en même temps
+    pub fire_cooldown: f32,    // Délai minimal entre deux tirs, en secondes
+    pub powerup_chance: f32,   // Probabilité qu'un astéroïde détruit par un tir lâche un bonus
+    pub powerup_lifetime: f32, // Durée avant disparition d'un bonus non ramassé, en secondes
+    pub hyperspace_cooldown: f32, // Délai minimal entre deux sauts en hyperespace, en secondes
+    pub hyperspace_failure: f32, // Probabilité qu'un saut en hyperespace détruise le vaisseau
+    pub bash_radius: f32, // Portée de l'onde de choc déclenchée en consommant `bash_cost` d'énergie du bouclier
+    pub bash_speed: f32, // Vitesse donnée aux astéroïdes repoussés par l'onde de choc, en pixels par seconde
+    pub respawn_radius: f32, // Rayon autour du centre de l'arène qui doit être libre d'astéroïdes pour que le vaisseau détruit réapparaisse
+    pub respawn_grace: f32, // Durée de la période de grâce (vaisseau invincible et clignotant) après réapparition, en secondes
+    pub initial_lives: u32, // Nombre de vies en début de partie
+    pub extra_life_score: u32, // Points à marquer pour gagner une vie supplémentaire (0 pour aucune)
+    pub shield_regen: f32,     // Énergie regagnée par le bouclier chaque seconde (sur 100)
+    pub collision_damage: f32, // Dégâts d'une collision par pixel de rayon de l'objet percuté, à la vitesse relative de référence
+    pub bullet_damage: f32,    // Dégâts d'un projectile ennemi
+    pub bash_cost: f32,        // Énergie du bouclier consommée par l'onde de choc
+    pub restitution: f32, // Coefficient de restitution des chocs entre astéroïdes : 1 pour un choc élastique, 0 pour des astéroïdes qui ne rebondissent pas
+    pub large_fragments: usize, // Nombre de fragments d'un grand astéroïde divisé (au moins 2)
+}
+
+impl Default for GameplayConfig {
+    /// Réglages par défaut du jeu.
+    fn default() -> Self {
+        Self {
+            well_strength: 400_000.0,
+            well_horizon: 25.0,
+            missile_lifetime: 1.2,
+            max_missiles: 5,
+            fire_cooldown: 0.15,
+            powerup_chance: 0.08,
+            powerup_lifetime: 8.0,
+            hyperspace_cooldown: 3.0,
+            hyperspace_failure: 0.05,
+            bash_radius: 150.0,
+            bash_speed: 150.0,
+            respawn_radius: 150.0,
+            respawn_grace: 2.0,
+            initial_lives: 3,
+            extra_life_score: 500,
+            shield_regen: 5.0,
+            collision_damage: 0.5,
+            bullet_damage: 25.0,
+            bash_cost: 40.0,
+            restitution: 1.0,
+            large_fragments: 2,
+        }
+    }
+}
+
+impl GameplayConfig {
+    /// Retourne les réglages ramenés dans leurs plages valides (voir `field_range`). Un réglage
+    /// indéterminé (`NaN`) reprend sa valeur par défaut.
+    pub fn clamped(self) -> Self {
+        let mut config = self;
+        for ((name, value), (_, default)) in self.fields().into_iter().zip(Self::default().fields())
+        {
+            let Some(range) = Self::field_range(name) else {
+                continue;
+            };
+            let value = if value.is_nan() {
+                default
+            } else {
+                value.clamp(*range.start(), *range.end())
+            };
+            config.set_field(name, value);
+        }
+        config
+    }
+
+    /// Retourne la plage des valeurs acceptées pour un réglage, ou `None` si le nom est inconnu.
+    pub fn field_range(name: &str) -> Option<RangeInclusive<f64>> {
+        let range = match name {
+            "well_strength" => 0.0..=1e9,
+            "well_horizon" | "bash_radius" | "bash_speed" | "respawn_radius" => 0.0..=10_000.0,
+            "missile_lifetime" | "fire_cooldown" | "respawn_grace" => 0.0..=60.0,
+            "max_missiles" => 1.0..=100.0,
+            "powerup_chance" | "hyperspace_failure" | "restitution" => 0.0..=1.0,
+            "powerup_lifetime" | "hyperspace_cooldown" => 0.0..=3600.0,
+            "initial_lives" => 1.0..=99.0,
+            "extra_life_score" => 0.0..=1e9,
+            "shield_regen" | "collision_damage" | "bullet_damage" | "bash_cost" => 0.0..=1000.0,
+            "large_fragments" => 2.0..=8.0,
+            _ => return None,
+        };
+        Some(range)
+    }
+
+    /// Retourne chaque réglage avec son nom, pour l'enregistrement dans les replays.
+    pub fn fields(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("well_strength", self.well_strength as f64),
+            ("well_horizon", self.well_horizon as f64),
+            ("missile_lifetime", self.missile_lifetime as f64),
+            ("max_missiles", self.max_missiles as f64),
+            ("fire_cooldown", self.fire_cooldown as f64),
+            ("powerup_chance", self.powerup_chance as f64),
+            ("powerup_lifetime", self.powerup_lifetime as f64),
+            ("hyperspace_cooldown", self.hyperspace_cooldown as f64),
+            ("hyperspace_failure", self.hyperspace_failure as f64),
+            ("bash_radius", self.bash_radius as f64),
+            ("bash_speed", self.bash_speed as f64),
+            ("respawn_radius", self.respawn_radius as f64),
+            ("respawn_grace", self.respawn_grace as f64),
+            ("initial_lives", self.initial_lives as f64),
+            ("extra_life_score", self.extra_life_score as f64),
+            ("shield_regen", self.shield_regen as f64),
+            ("collision_damage", self.collision_damage as f64),
+            ("bullet_damage", self.bullet_damage as f64),
+            ("bash_cost", self.bash_cost as f64),
+            ("restitution", self.restitution as f64),
+            ("large_fragments", self.large_fragments as f64),
+        ]
+    }
+
+    /// Modifie un réglage à partir de son nom.
+    ///
+    /// # Arguments
+    /// * `name` - Le nom du réglage, tel que donné par `fields`.
+    /// * `value` - Sa nouvelle valeur.
+    ///
+    /// # Retourne
+    /// * `bool` - `false` si le nom est inconnu ou la valeur hors de sa plage (`field_range`),
+    ///   auquel cas rien n'est modifié.
+    pub fn set_field(&mut self, name: &str, value: f64) -> bool {
+        if !Self::field_range(name).is_some_and(|range| range.contains(&value)) {
+            return false;
+        }
+        match name {
+            "well_strength" => self.well_strength = value as f32,
+            "well_horizon" => self.well_horizon = value as f32,
+            "missile_lifetime" => self.missile_lifetime = value as f32,
+            "max_missiles" => self.max_missiles = value as usize,
+            "fire_cooldown" => self.fire_cooldown = value as f32,
+            "powerup_chance" => self.powerup_chance = value as f32,
+            "powerup_lifetime" => self.powerup_lifetime = value as f32,
+            "hyperspace_cooldown" => self.hyperspace_cooldown = value as f32,
+            "hyperspace_failure" => self.hyperspace_failure = value as f32,
+            "bash_radius" => self.bash_radius = value as f32,
+            "bash_speed" => self.bash_speed = value as f32,
+            "respawn_radius" => self.respawn_radius = value as f32,
+            "respawn_grace" => self.respawn_grace = value as f32,
+            "initial_lives" => self.initial_lives = value as u32,
+            "extra_life_score" => self.extra_life_score = value as u32,
+            "shield_regen" => self.shield_regen = value as f32,
+            "collision_damage" => self.collision_damage = value as f32,
+            "bullet_damage" => self.bullet_damage = value as f32,
+            "bash_cost" => self.bash_cost = value as f32,
+            "restitution" => self.restitution = value as f32,
+            "large_fragments" => self.large_fragments = value as usize,
+            _ => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_field_can_be_set_by_name() {
+        let config = GameplayConfig::default();
+        for (name, value) in config.fields() {
+            let range = GameplayConfig::field_range(name).unwrap();
+            let other = if value == *range.start() {
+                *range.end()
+            } else {
+                *range.start()
+            };
+            let mut changed = config;
+            assert!(changed.set_field(name, other), "{}", name);
+            assert!(changed.fields().contains(&(name, other)), "{}", name);
+        }
+        assert!(!GameplayConfig::default().set_field("inconnu", 0.0));
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected_or_clamped() {
+        let mut config = GameplayConfig::default();
+        assert!(!config.set_field("hyperspace_failure", f64::NAN));
+        assert!(!config.set_field("powerup_chance", 2.0));
+        assert!(!config.set_field("max_missiles", f64::INFINITY));
+        assert_eq!(config, GameplayConfig::default());
+
+        let config = GameplayConfig {
+            hyperspace_failure: f32::NAN,
+            fire_cooldown: -1.0,
+            missile_lifetime: f32::INFINITY,
+            max_missiles: 0,
+            ..Default::default()
+        }
+        .clamped();
+        assert_eq!(config.hyperspace_failure, 0.05);
+        assert_eq!(config.fire_cooldown, 0.0);
+        assert_eq!(config.missile_lifetime, 60.0);
+        assert_eq!(config.max_missiles, 1);
+    }
+}
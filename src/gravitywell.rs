@@ -0,0 +1,164 @@
+//! Module `gravitywell.rs`
+//!
+//! Ce module définit la structure `GravityWell`, un puits de gravité (trou noir) qui attire le
+//! vaisseau, les missiles et les astéroïdes, et avale ceux qui franchissent son horizon.
+
+use crate::arena;
+use crate::stellarobject::StellarObject;
+use ::rand::Rng;
+use macroquad::math::{vec2, Vec2};
+
+/// Structure `GravityWell` représentant un puits de gravité immobile.
+///
+/// L'accélération qu'il exerce décroît comme l'inverse du carré de la distance, mesurée dans
+/// l'arène torique. Elle est plafonnée à sa valeur sur l'horizon pour rester finie près du centre.
+pub struct GravityWell {
+    position: Vec2, // Position du puits
+    strength: f32,  // Intensité de l'attraction, en pixels cubes par seconde au carré
+    horizon: f32,   // Rayon de l'horizon : tout objet dont le centre le franchit est avalé
+}
+
+impl GravityWell {
+    // Distance minimale entre le vaisseau et un puits qui apparaît
+    pub const SAFE_DISTANCE: f32 = 250.0;
+    // Nombre de tirages au plus pour trouver une position assez loin des zones à éviter
+    const MAX_PLACEMENT_TRIES: usize = 20;
+
+    /// Crée un nouveau puits de gravité.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - La position du puits.
+    /// * `strength` - L'intensité de l'attraction : l'accélération vaut `strength / distance²`.
+    /// * `horizon` - Le rayon de l'horizon du puits.
+    pub fn new(position: Vec2, strength: f32, horizon: f32) -> Self {
+        Self {
+            position,
+            strength,
+            horizon,
+        }
+    }
+
+    /// Crée un puits de gravité à une position aléatoire, si possible loin des zones à éviter.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    /// * `avoid` - Les zones à éviter (centre et rayon), comme le vaisseau et sa zone de
+    ///   réapparition.
+    /// * `strength` - L'intensité de l'attraction.
+    /// * `horizon` - Le rayon de l'horizon du puits.
+    /// * `rng` - Le générateur aléatoire de la partie.
+    pub fn new_alea(
+        bounds: Vec2,
+        avoid: &[(Vec2, f32)],
+        strength: f32,
+        horizon: f32,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let mut position = Vec2::ZERO;
+        for _ in 0..Self::MAX_PLACEMENT_TRIES {
+            position = vec2(rng.gen_range(0.0..bounds.x), rng.gen_range(0.0..bounds.y));
+            if avoid.iter().all(|&(center, radius)| {
+                arena::wrapped_delta(position, center, bounds).length() >= radius
+            }) {
+                break;
+            }
+        }
+        Self::new(position, strength, horizon)
+    }
+
+    /// Calcule l'accélération exercée par le puits sur un objet.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - La position de l'objet attiré.
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    ///
+    /// # Retourne
+    ///
+    /// * `Vec2` - L'accélération, dirigée vers le puits, en pixels par seconde au carré.
+    pub fn acceleration_at(&self, position: Vec2, bounds: Vec2) -> Vec2 {
+        let delta = arena::wrapped_delta(position, self.position, bounds);
+        let distance = delta.length().max(self.horizon);
+        match delta.try_normalize() {
+            Some(direction) => direction * self.strength / (distance * distance),
+            None => Vec2::ZERO,
+        }
+    }
+
+    /// Indique si un objet a franchi l'horizon du puits et doit être avalé.
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - L'objet à tester, dont seul le centre compte.
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    pub fn swallows(&self, object: &dyn StellarObject, bounds: Vec2) -> bool {
+        arena::wrapped_delta(object.position(), self.position, bounds).length() < self.horizon
+    }
+}
+
+impl StellarObject for GravityWell {
+    /// Retourne la position du puits.
+    fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Le puits est immobile : sa position précédente est sa position.
+    fn previous_position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Le puits est immobile.
+    fn update_position(&mut self, _bounds: Vec2, _dt: f32) {}
+
+    /// Retourne le rayon de l'horizon du puits.
+    fn radius(&self) -> f32 {
+        self.horizon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    #[test]
+    fn test_attraction_follows_inverse_square() {
+        let bounds = vec2(1000.0, 1000.0);
+        let well = GravityWell::new(vec2(500.0, 500.0), 1000.0, 10.0);
+
+        let near = well.acceleration_at(vec2(520.0, 500.0), bounds);
+        let far = well.acceleration_at(vec2(540.0, 500.0), bounds);
+        assert!(near.x < 0.0 && near.y == 0.0);
+        assert!((near.length() / far.length() - 4.0).abs() < 1e-3);
+
+        // Plafonnée à l'intérieur de l'horizon, nulle au centre.
+        let inside = well.acceleration_at(vec2(505.0, 500.0), bounds);
+        assert!((inside.length() - 1000.0 / 100.0).abs() < 1e-3);
+        assert_eq!(well.acceleration_at(well.position, bounds), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_attraction_wraps_around_edges() {
+        let bounds = vec2(1000.0, 1000.0);
+        let well = GravityWell::new(vec2(10.0, 500.0), 1000.0, 10.0);
+
+        // Un objet près du bord droit est attiré vers la droite, à travers le bord.
+        assert!(well.acceleration_at(vec2(990.0, 500.0), bounds).x > 0.0);
+    }
+
+    #[test]
+    fn test_random_wells_avoid_every_zone() {
+        let bounds = vec2(1000.0, 1000.0);
+        let avoid = [(vec2(100.0, 100.0), 250.0), (bounds / 2.0, 200.0)];
+        let mut rng = SmallRng::seed_from_u64(7);
+        for _ in 0..100 {
+            let well = GravityWell::new_alea(bounds, &avoid, 1000.0, 10.0, &mut rng);
+            for (center, radius) in avoid {
+                assert!(arena::wrapped_delta(well.position, center, bounds).length() >= radius);
+            }
+        }
+    }
+}
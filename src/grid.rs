@@ -0,0 +1,240 @@
+//! Module `grid.rs`
+//!
+//! Ce module définit la structure `UniformGrid`, une grille uniforme utilisée comme première phase
+//! de la détection des collisions : seuls les objets situés dans des cellules voisines sont comparés,
+//! au lieu de tester toutes les paires possibles.
+
+use crate::stellarobject::StellarObject;
+use macroquad::math::{vec2, Vec2};
+
+/// Structure `UniformGrid` qui répartit des objets stellaires dans des cellules rectangulaires.
+///
+/// Chaque objet est inscrit dans toutes les cellules recouvertes par le carré qui l'englobe.
+/// L'arène étant torique, la grille l'est aussi : un objet qui déborde d'un bord est également
+/// inscrit dans les cellules du bord opposé.
+pub struct UniformGrid {
+    cell_size: Vec2,        // Dimensions d'une cellule, qui divisent exactement l'arène
+    columns: usize,         // Nombre de colonnes
+    rows: usize,            // Nombre de lignes
+    cells: Vec<Vec<usize>>, // Indices des objets inscrits dans chaque cellule
+}
+
+impl UniformGrid {
+    /// Crée une grille vide couvrant l'arène.
+    ///
+    /// # Arguments
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    /// * `cell_size` - Côté minimal d'une cellule, idéalement de l'ordre du diamètre des plus gros
+    ///   objets. Les cellules sont agrandies pour que leur nombre divise exactement l'arène.
+    pub fn new(bounds: Vec2, cell_size: f32) -> Self {
+        let columns = ((bounds.x / cell_size).floor() as usize).max(1);
+        let rows = ((bounds.y / cell_size).floor() as usize).max(1);
+        Self {
+            cell_size: bounds / vec2(columns as f32, rows as f32),
+            columns,
+            rows,
+            cells: vec![Vec::new(); columns * rows],
+        }
+    }
+
+    /// Vide la grille puis y inscrit tous les objets, repérés par leur indice dans `objects`.
+    pub fn rebuild<T: StellarObject>(&mut self, objects: &[T]) {
+        for cell in self.cells.iter_mut() {
+            cell.clear();
+        }
+        for (index, object) in objects.iter().enumerate() {
+            for cell in self.covered_cells(object.position(), object.radius()) {
+                self.cells[cell].push(index);
+            }
+        }
+    }
+
+    /// Retourne les indices des objets susceptibles de toucher un cercle.
+    ///
+    /// # Arguments
+    /// * `position` - Centre du cercle.
+    /// * `radius` - Rayon du cercle.
+    /// * `candidates` - Vecteur rempli avec les indices candidats, triés et sans doublon.
+    pub fn query(&self, position: Vec2, radius: f32, candidates: &mut Vec<usize>) {
+        candidates.clear();
+        for cell in self.covered_cells(position, radius) {
+            candidates.extend_from_slice(&self.cells[cell]);
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+    }
+
+    /// Trouve toutes les paires `(i, j)` telles que `hit(&others[i], &objects[j])` soit vrai,
+    /// `objects` étant la liste ayant servi à construire la grille.
+    ///
+    /// # Retourne
+    /// * `Vec<(usize, usize)>` - Les paires en collision, triées par `i` puis par `j`.
+    pub fn find_pairs<A: StellarObject, B>(
+        &self,
+        others: &[A],
+        objects: &[B],
+        hit: impl Fn(&A, &B) -> bool,
+    ) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        let mut candidates = Vec::new();
+        for (i, other) in others.iter().enumerate() {
+            self.query(other.position(), other.radius(), &mut candidates);
+            pairs.extend(
+                candidates
+                    .iter()
+                    .filter(|&&j| hit(other, &objects[j]))
+                    .map(|&j| (i, j)),
+            );
+        }
+        pairs
+    }
+
+    /// Retourne les indices des cellules recouvertes par un cercle, en repliant sur le bord opposé
+    /// les cellules situées hors de l'arène. Chaque cellule n'apparaît qu'une fois.
+    fn covered_cells(&self, position: Vec2, radius: f32) -> impl Iterator<Item = usize> {
+        let columns = Self::wrapped_range(position.x, radius, self.cell_size.x, self.columns);
+        let rows = Self::wrapped_range(position.y, radius, self.cell_size.y, self.rows);
+        let width = self.columns;
+        rows.flat_map(move |row| columns.clone().map(move |column| row * width + column))
+    }
+
+    /// Calcule les indices, repliés dans `[0, count)`, des cellules recouvertes par l'intervalle
+    /// `[center - radius, center + radius]` sur un axe.
+    fn wrapped_range(
+        center: f32,
+        radius: f32,
+        cell_size: f32,
+        count: usize,
+    ) -> impl Iterator<Item = usize> + Clone {
+        let first = ((center - radius) / cell_size).floor() as i64;
+        let last = ((center + radius) / cell_size).floor() as i64;
+        let span = (last - first + 1).clamp(1, count as i64);
+        (first..first + span).map(move |cell| cell.rem_euclid(count as i64) as usize)
+    }
+}
+
+/// Trouve toutes les paires `(i, j)` telles que `hit(&others[i], &objects[j])` soit vrai en testant
+/// toutes les combinaisons. Sert de référence pour vérifier et mesurer `UniformGrid::find_pairs`.
+///
+/// # Retourne
+/// * `Vec<(usize, usize)>` - Les paires en collision, triées par `i` puis par `j`.
+#[cfg(test)]
+pub fn find_pairs_naive<A, B>(
+    others: &[A],
+    objects: &[B],
+    hit: impl Fn(&A, &B) -> bool,
+) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (i, other) in others.iter().enumerate() {
+        for (j, object) in objects.iter().enumerate() {
+            if hit(other, object) {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::SmallRng;
+    use ::rand::{Rng, SeedableRng};
+    use std::time::Instant;
+
+    struct Circle {
+        position: Vec2,
+        radius: f32,
+    }
+
+    impl StellarObject for Circle {
+        fn position(&self) -> Vec2 {
+            self.position
+        }
+
+        fn previous_position(&self) -> Vec2 {
+            self.position
+        }
+
+        fn update_position(&mut self, _bounds: Vec2, _dt: f32) {}
+
+        fn radius(&self) -> f32 {
+            self.radius
+        }
+    }
+
+    /// Génère `n` cercles aléatoires, dont certains légèrement hors de l'arène.
+    fn random_circles(n: usize, bounds: Vec2, radii: &[f32], rng: &mut SmallRng) -> Vec<Circle> {
+        (0..n)
+            .map(|_| Circle {
+                position: vec2(
+                    rng.gen_range(-10.0..bounds.x + 10.0),
+                    rng.gen_range(-10.0..bounds.y + 10.0),
+                ),
+                radius: radii[rng.gen_range(0..radii.len())],
+            })
+            .collect()
+    }
+
+    fn overlap(a: &Circle, b: &Circle) -> bool {
+        a.position.distance(b.position) < a.radius + b.radius
+    }
+
+    #[test]
+    fn test_grid_matches_naive() {
+        let bounds = vec2(1280.0, 720.0);
+        let mut rng = SmallRng::seed_from_u64(7);
+        let asteroids = random_circles(300, bounds, &[15.0, 30.0, 60.0], &mut rng);
+        let missiles = random_circles(200, bounds, &[3.0], &mut rng);
+        let wrapped_overlap = |a: &Circle, b: &Circle| a.check_collision(b, bounds);
+
+        let mut grid = UniformGrid::new(bounds, 120.0);
+        grid.rebuild(&asteroids);
+
+        assert_eq!(
+            grid.find_pairs(&missiles, &asteroids, overlap),
+            find_pairs_naive(&missiles, &asteroids, overlap)
+        );
+        assert_eq!(
+            grid.find_pairs(&missiles, &asteroids, wrapped_overlap),
+            find_pairs_naive(&missiles, &asteroids, wrapped_overlap)
+        );
+    }
+
+    /// Compare le temps de la grille et de la méthode naïve sur une vague de plusieurs milliers
+    /// de fragments. À lancer avec `cargo test --release -- --ignored --nocapture bench`.
+    #[test]
+    #[ignore]
+    fn bench_grid_vs_naive() {
+        let bounds = vec2(1920.0, 1080.0);
+        let mut rng = SmallRng::seed_from_u64(42);
+        let asteroids = random_circles(4000, bounds, &[7.5, 15.0, 30.0], &mut rng);
+        let missiles = random_circles(1000, bounds, &[3.0], &mut rng);
+        let iterations = 20;
+
+        let start = Instant::now();
+        let mut naive = Vec::new();
+        for _ in 0..iterations {
+            naive = find_pairs_naive(&missiles, &asteroids, overlap);
+        }
+        let naive_time = start.elapsed() / iterations;
+
+        let start = Instant::now();
+        let mut grid = UniformGrid::new(bounds, 120.0);
+        let mut fast = Vec::new();
+        for _ in 0..iterations {
+            grid.rebuild(&asteroids);
+            fast = grid.find_pairs(&missiles, &asteroids, overlap);
+        }
+        let grid_time = start.elapsed() / iterations;
+
+        assert_eq!(naive, fast);
+        println!(
+            "{} astéroïdes, {} missiles : naïf {:?}, grille {:?} par pas",
+            asteroids.len(),
+            missiles.len(),
+            naive_time,
+            grid_time
+        );
+    }
+}
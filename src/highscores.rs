@@ -0,0 +1,217 @@
+//! Module `highscores.rs`
+//!
+//! Ce module définit le tableau des meilleurs scores (`HighScores`), conservé d'une partie à
+//! l'autre dans un fichier du dossier de données de l'utilisateur. Le chargement tolère un fichier
+//! absent, tronqué ou corrompu : le jeu démarre alors avec les scores qu'il a pu relire.
+
+use crate::storage;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Signature placée au début du fichier des meilleurs scores.
+const MAGIC: &[u8; 4] = b"ASTS";
+
+/// Version du format de fichier.
+const VERSION: u8 = 1;
+
+/// Nom du fichier des meilleurs scores, dans le dossier de données du jeu.
+const FILE_NAME: &str = "highscores.dat";
+
+/// Un score du tableau.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighScore {
+    pub name: String, // Nom saisi par le joueur
+    pub score: i32,   // Score final de la partie
+    pub level: u32,   // Vague atteinte
+}
+
+/// Structure `HighScores` qui représente le tableau des meilleurs scores, du plus haut au plus bas.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HighScores {
+    entries: Vec<HighScore>, // Scores classés, au plus `MAX_ENTRIES`
+}
+
+impl HighScores {
+    /// Nombre de scores conservés dans le tableau.
+    pub const MAX_ENTRIES: usize = 10;
+
+    /// Longueur maximale d'un nom, en caractères.
+    pub const MAX_NAME_LENGTH: usize = 12;
+
+    /// Retourne les scores, du plus haut au plus bas.
+    pub fn entries(&self) -> &[HighScore] {
+        &self.entries
+    }
+
+    /// Indique si un score entrerait dans le tableau.
+    pub fn qualifies(&self, score: i32) -> bool {
+        self.entries.len() < Self::MAX_ENTRIES
+            || self.entries.last().is_some_and(|last| score > last.score)
+    }
+
+    /// Ajoute un score au tableau, après les scores égaux déjà présents.
+    ///
+    /// # Retourne
+    /// * `Option<usize>` - Le rang du score (à partir de 0), ou `None` s'il n'entre pas dans le tableau.
+    pub fn insert(&mut self, entry: HighScore) -> Option<usize> {
+        let rank = self
+            .entries
+            .partition_point(|best| best.score >= entry.score);
+        if rank >= Self::MAX_ENTRIES {
+            return None;
+        }
+        self.entries.insert(rank, entry);
+        self.entries.truncate(Self::MAX_ENTRIES);
+        Some(rank)
+    }
+
+    /// Retourne le chemin du fichier des meilleurs scores dans le dossier de données du jeu, ou
+    /// `None` si ce dossier est introuvable.
+    pub fn default_path() -> Option<PathBuf> {
+        storage::data_dir().map(|dir| dir.join(FILE_NAME))
+    }
+
+    /// Charge le tableau depuis un fichier.
+    ///
+    /// Un fichier absent donne un tableau vide. Un fichier illisible ou d'une autre version est
+    /// ignoré, et seuls les scores lus avant une éventuelle corruption sont conservés.
+    pub fn load(path: impl AsRef<Path>) -> Self {
+        match File::open(path) {
+            Ok(file) => Self::read_from(&mut BufReader::new(file)),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Enregistre le tableau dans un fichier, en créant son dossier si besoin.
+    ///
+    /// Le tableau est d'abord écrit dans un fichier temporaire, renommé ensuite : une
+    /// interruption pendant l'écriture ne peut pas corrompre le tableau existant.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temporary = path.with_extension("tmp");
+        let mut writer = BufWriter::new(File::create(&temporary)?);
+        self.write_to(&mut writer)?;
+        writer.flush()?;
+        drop(writer);
+        fs::rename(temporary, path)
+    }
+
+    /// Écrit le tableau au format binaire.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&[self.entries.len() as u8])?;
+        for entry in &self.entries {
+            let name = truncate_name(&entry.name);
+            writer.write_all(&[name.len() as u8])?;
+            writer.write_all(name.as_bytes())?;
+            writer.write_all(&entry.score.to_le_bytes())?;
+            writer.write_all(&entry.level.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Lit un tableau écrit par `write_to`, en conservant tout ce qui a pu être lu.
+    pub fn read_from(reader: &mut impl Read) -> Self {
+        let mut scores = Self::default();
+        let mut header = [0u8; 6];
+        if reader.read_exact(&mut header).is_err() || &header[..4] != MAGIC || header[4] != VERSION
+        {
+            return scores;
+        }
+        for _ in 0..header[5] {
+            match read_entry(reader) {
+                Ok(entry) => {
+                    scores.insert(entry);
+                }
+                Err(_) => break,
+            }
+        }
+        scores
+    }
+}
+
+/// Lit un score du tableau.
+fn read_entry(reader: &mut impl Read) -> io::Result<HighScore> {
+    let mut length = [0u8; 1];
+    reader.read_exact(&mut length)?;
+    let mut name = vec![0u8; length[0] as usize];
+    reader.read_exact(&mut name)?;
+    let mut score = [0u8; 4];
+    reader.read_exact(&mut score)?;
+    let mut level = [0u8; 4];
+    reader.read_exact(&mut level)?;
+    Ok(HighScore {
+        name: truncate_name(&String::from_utf8_lossy(&name)).to_owned(),
+        score: i32::from_le_bytes(score),
+        level: u32::from_le_bytes(level),
+    })
+}
+
+/// Coupe un nom à `MAX_NAME_LENGTH` caractères.
+fn truncate_name(name: &str) -> &str {
+    match name.char_indices().nth(HighScores::MAX_NAME_LENGTH) {
+        Some((end, _)) => &name[..end],
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, score: i32) -> HighScore {
+        HighScore {
+            name: name.to_owned(),
+            score,
+            level: 1,
+        }
+    }
+
+    #[test]
+    fn test_table_keeps_the_best_scores() {
+        let mut scores = HighScores::default();
+        for score in 0..HighScores::MAX_ENTRIES as i32 {
+            assert!(scores.qualifies(score * 10));
+            scores.insert(entry("AAA", score * 10));
+        }
+        assert_eq!(scores.entries()[0].score, 90);
+        assert!(!scores.qualifies(0));
+        assert_eq!(scores.insert(entry("BBB", 0)), None);
+        assert_eq!(scores.insert(entry("CCC", 45)), Some(5));
+        assert_eq!(scores.entries().len(), HighScores::MAX_ENTRIES);
+        assert_eq!(scores.entries().last().unwrap().score, 10);
+    }
+
+    #[test]
+    fn test_round_trip_and_corruption() {
+        let mut scores = HighScores::default();
+        scores.insert(entry("Ilyan", 120));
+        scores.insert(entry("Thomas", 80));
+        scores.insert(entry("Un nom beaucoup trop long", 40));
+        let mut bytes = Vec::new();
+        scores.write_to(&mut bytes).unwrap();
+
+        let loaded = HighScores::read_from(&mut bytes.as_slice());
+        assert_eq!(loaded.entries()[..2], scores.entries()[..2]);
+        assert_eq!(loaded.entries()[2].name, "Un nom beauc");
+
+        // Un fichier tronqué garde les scores lus en entier, un fichier étranger n'en garde aucun.
+        bytes.truncate(bytes.len() - 3);
+        assert_eq!(
+            HighScores::read_from(&mut bytes.as_slice()).entries().len(),
+            2
+        );
+        bytes[4] = VERSION + 1;
+        assert!(HighScores::read_from(&mut bytes.as_slice())
+            .entries()
+            .is_empty());
+        assert!(HighScores::read_from(&mut b"".as_slice())
+            .entries()
+            .is_empty());
+    }
+}
@@ -2,57 +2,93 @@
 //!
 //! Ce module définit la structure `Missile`.
 
+use crate::arena;
 use crate::stellarobject::StellarObject;
-use macroquad::prelude::*;
+use macroquad::math::Vec2;
 
 /// Structure `Missile` qui représente les missiles du jeu.
 ///
-/// Le missile a une position, une vitesse, et un rayon. Il peut être déplacé à une vitesse constante
-/// et peut être dessiné à l'écran. Il vérifie également si son positionnement sort des limites de l'écran.
+/// Le missile a une position, une vitesse, un rayon et une durée de vie. Il se déplace à une vitesse
+/// constante, réapparaît du côté opposé lorsqu'il sort de l'arène et disparaît à la fin de sa
+/// durée de vie, ce qui limite sa portée. Un missile guidé tourne en plus vers sa cible.
 pub struct Missile {
-    position: Vec2, // Position actuelle du missile
-    velocity: Vec2, // Vitesse du missile (direction et intensité)
-    radius: f32,    // Rayon du missile (utilisé pour le dessin et la détection des collisions)
+    position: Vec2,          // Position actuelle du missile
+    previous_position: Vec2, // Position du missile avant le dernier pas
+    velocity: Vec2,          // Vitesse du missile en pixels par seconde (direction et intensité)
+    lifetime: f32,           // Durée de vie restante, en secondes
+    homing: bool,            // Missile guidé vers l'astéroïde le plus proche
+    radius: f32, // Rayon du missile (utilisé pour le dessin et la détection des collisions)
 }
 
 impl Missile {
-    /// Crée un nouveau missile avec une position et un angle de lancement.
+    // Vitesse d'un missile du canon, en pixels par seconde
+    pub const MISSILE_VITESSE: f32 = 300.0;
+    // Vitesse de rotation maximale d'un missile guidé, en radians par seconde
+    pub const HOMING_TURN_RATE: f32 = 3.0;
+
+    /// Crée un nouveau missile. Les caractéristiques propres à chaque arme sont fixées par
+    /// `Weapon::missiles`.
     ///
     /// # Arguments
     ///
     /// * `position` - La position initiale du missile.
-    /// * `angle` - L'angle de lancement en radians pour déterminer la direction du missile.
-    ///
-    /// # Retourne
-    ///
-    /// Un nouveau missile avec une vitesse calculée à partir de l'angle et une taille fixe.
-    pub fn new(position: Vec2, angle: f32) -> Self {
+    /// * `velocity` - La vitesse initiale du missile, vitesse du vaisseau comprise.
+    /// * `radius` - Le rayon du missile.
+    /// * `lifetime` - La durée de vie du missile, en secondes.
+    /// * `homing` - `true` pour un missile guidé.
+    pub fn new(position: Vec2, velocity: Vec2, radius: f32, lifetime: f32, homing: bool) -> Self {
         Self {
             position,
-            velocity: vec2(angle.cos(), angle.sin()) * 5.0, // Vitesse initiale basée sur l'angle
-            radius: 3.0,                                    // Rayon du missile
+            previous_position: position,
+            velocity,
+            lifetime,
+            homing,
+            radius,
         }
     }
 
-    /// Dessine le missile à l'écran s'il n'est pas hors des limites.
+    /// Indique si le missile est guidé.
+    pub fn is_homing(&self) -> bool {
+        self.homing
+    }
+
+    /// Fait tourner la vitesse d'un missile guidé vers sa cible, sans changer sa norme et sans
+    /// dépasser `HOMING_TURN_RATE`.
+    ///
+    /// # Arguments
     ///
-    /// Le missile est représenté par un petit cercle rempli de couleur rouge.
-    pub fn draw(&self) {
-        if !self.is_out_of_bounds() {
-            draw_circle(self.position.x, self.position.y, self.radius, RED); // Dessin du missile
+    /// * `to_target` - Le déplacement du missile vers sa cible.
+    /// * `dt` - La durée du pas, en secondes.
+    pub fn steer_towards(&mut self, to_target: Vec2, dt: f32) {
+        let turn = self.velocity.angle_between(to_target);
+        if turn.is_finite() {
+            let max_turn = Self::HOMING_TURN_RATE * dt;
+            self.velocity = Vec2::from_angle(turn.clamp(-max_turn, max_turn)).rotate(self.velocity);
         }
     }
 
-    /// Vérifie si le missile est hors des limites de l'écran.
+    /// Retourne la vitesse du missile, en pixels par seconde.
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Modifie la vitesse du missile sous l'effet d'une accélération extérieure.
+    ///
+    /// # Arguments
+    ///
+    /// * `acceleration` - L'accélération subie, en pixels par seconde au carré.
+    /// * `dt` - La durée pendant laquelle elle s'applique, en secondes.
+    pub fn accelerate(&mut self, acceleration: Vec2, dt: f32) {
+        self.velocity += acceleration * dt;
+    }
+
+    /// Vérifie si le missile a atteint la fin de sa durée de vie.
     ///
     /// # Retourne
     ///
-    /// `true` si le missile dépasse les bords de l'écran, `false` sinon.
-    pub fn is_out_of_bounds(&self) -> bool {
-        self.position.x < 0.0
-            || self.position.x > screen_width()
-            || self.position.y < 0.0
-            || self.position.y > screen_height() // Vérifie les limites de l'écran
+    /// `true` si le missile doit disparaître, `false` sinon.
+    pub fn is_expired(&self) -> bool {
+        self.lifetime <= 0.0
     }
 }
 
@@ -66,11 +102,19 @@ impl StellarObject for Missile {
         self.position
     }
 
+    /// Retourne la position du missile avant le dernier pas.
+    fn previous_position(&self) -> Vec2 {
+        self.previous_position
+    }
+
     /// Met à jour la position du missile en fonction de sa vitesse.
     ///
-    /// Cette méthode déplace le missile dans la direction de sa vitesse à chaque mise à jour du jeu.
-    fn update_position(&mut self) {
-        self.position += self.velocity; // Déplace le missile
+    /// Cette méthode déplace le missile dans la direction de sa vitesse à chaque mise à jour du jeu,
+    /// le fait réapparaître du côté opposé s'il sort de l'arène et décompte sa durée de vie.
+    fn update_position(&mut self, bounds: Vec2, dt: f32) {
+        (self.position, self.previous_position) =
+            arena::move_wrapped(self.position, self.velocity * dt, bounds); // Déplace le missile
+        self.lifetime -= dt;
     }
 
     /// Retourne le rayon du missile.
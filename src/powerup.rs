@@ -0,0 +1,167 @@
+//! Module `powerup.rs`
+//!
+//! Ce module définit les bonus (`PowerUp`) lâchés de temps en temps par les astéroïdes détruits.
+//! Un bonus dérive lentement, réapparaît du côté opposé lorsqu'il sort de l'arène, disparaît au
+//! bout d'un moment et s'applique lorsque le vaisseau le touche.
+
+use crate::arena;
+use crate::stellarobject::StellarObject;
+use ::rand::Rng;
+use macroquad::math::Vec2;
+
+/// Effets possibles d'un bonus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerUpKind {
+    ShieldRecharge,  // Recharge complète du bouclier
+    WeaponUpgrade,   // Passage à l'arme suivante
+    Invincibility,   // Invincibilité temporaire
+    ScoreMultiplier, // Points doublés pendant un moment
+    SlowTime,        // Astéroïdes ralentis pendant un moment
+}
+
+impl PowerUpKind {
+    /// Tous les effets, équiprobables lors d'un tirage.
+    pub const ALL: [PowerUpKind; 5] = [
+        PowerUpKind::ShieldRecharge,
+        PowerUpKind::WeaponUpgrade,
+        PowerUpKind::Invincibility,
+        PowerUpKind::ScoreMultiplier,
+        PowerUpKind::SlowTime,
+    ];
+}
+
+/// Structure `PowerUp` représentant un bonus à ramasser.
+pub struct PowerUp {
+    kind: PowerUpKind,       // Effet du bonus
+    position: Vec2,          // Position actuelle du bonus
+    previous_position: Vec2, // Position du bonus avant le dernier pas
+    velocity: Vec2,          // Vitesse de dérive, en pixels par seconde
+    lifetime: f32,           // Durée restante avant disparition, en secondes
+}
+
+impl PowerUp {
+    // Rayon d'un bonus
+    pub const RADIUS: f32 = 12.0;
+    // Part de la vitesse de l'astéroïde détruit conservée par le bonus
+    const DRIFT: f32 = 0.3;
+
+    /// Crée un nouveau bonus.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - L'effet du bonus.
+    /// * `position` - La position initiale du bonus.
+    /// * `velocity` - La vitesse de dérive du bonus.
+    /// * `lifetime` - La durée avant disparition, en secondes.
+    pub fn new(kind: PowerUpKind, position: Vec2, velocity: Vec2, lifetime: f32) -> Self {
+        Self {
+            kind,
+            position,
+            previous_position: position,
+            velocity,
+            lifetime,
+        }
+    }
+
+    /// Tire au sort un bonus lâché par un astéroïde détruit.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - La position de l'astéroïde détruit.
+    /// * `speed` - La vitesse de l'astéroïde détruit, dont le bonus garde une part.
+    /// * `chance` - La probabilité qu'un bonus soit lâché, entre 0 et 1.
+    /// * `lifetime` - La durée avant disparition du bonus, en secondes.
+    /// * `rng` - Le générateur aléatoire de la partie.
+    ///
+    /// # Retourne
+    ///
+    /// * `Option<PowerUp>` - Le bonus lâché, d'un effet choisi au hasard, ou `None`.
+    pub fn maybe_drop(
+        position: Vec2,
+        speed: Vec2,
+        chance: f32,
+        lifetime: f32,
+        rng: &mut impl Rng,
+    ) -> Option<Self> {
+        if !rng.gen_bool(chance.clamp(0.0, 1.0) as f64) {
+            return None;
+        }
+        let kind = PowerUpKind::ALL[rng.gen_range(0..PowerUpKind::ALL.len())];
+        Some(Self::new(kind, position, speed * Self::DRIFT, lifetime))
+    }
+
+    /// Retourne l'effet du bonus.
+    pub fn kind(&self) -> PowerUpKind {
+        self.kind
+    }
+
+    /// Retourne la durée restante avant disparition, en secondes.
+    pub fn lifetime(&self) -> f32 {
+        self.lifetime
+    }
+
+    /// Indique si le bonus a disparu sans avoir été ramassé.
+    pub fn is_expired(&self) -> bool {
+        self.lifetime <= 0.0
+    }
+}
+
+impl StellarObject for PowerUp {
+    /// Retourne la position actuelle du bonus.
+    fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Retourne la position du bonus avant le dernier pas.
+    fn previous_position(&self) -> Vec2 {
+        self.previous_position
+    }
+
+    /// Fait dériver le bonus dans l'arène et décompte sa durée de vie.
+    fn update_position(&mut self, bounds: Vec2, dt: f32) {
+        (self.position, self.previous_position) =
+            arena::move_wrapped(self.position, self.velocity * dt, bounds);
+        self.lifetime -= dt;
+    }
+
+    /// Retourne le rayon du bonus.
+    fn radius(&self) -> f32 {
+        Self::RADIUS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+    use macroquad::math::vec2;
+
+    #[test]
+    fn test_drop_chance() {
+        let mut rng = SmallRng::seed_from_u64(16);
+        let position = vec2(100.0, 100.0);
+
+        assert!(PowerUp::maybe_drop(position, Vec2::ZERO, 0.0, 5.0, &mut rng).is_none());
+        let powerup = PowerUp::maybe_drop(position, vec2(100.0, 0.0), 1.0, 5.0, &mut rng).unwrap();
+        assert_eq!(powerup.position(), position);
+        assert!(powerup.velocity.x > 0.0 && powerup.velocity.x < 100.0);
+    }
+
+    #[test]
+    fn test_powerup_drifts_wraps_and_expires() {
+        let bounds = vec2(200.0, 200.0);
+        let mut powerup = PowerUp::new(
+            PowerUpKind::SlowTime,
+            vec2(195.0, 100.0),
+            vec2(60.0, 0.0),
+            1.0,
+        );
+
+        powerup.update_position(bounds, 0.5);
+        assert_eq!(powerup.position(), vec2(25.0, 100.0));
+        assert!(!powerup.is_expired());
+        powerup.update_position(bounds, 0.5);
+        assert!(powerup.is_expired());
+    }
+}
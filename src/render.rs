@@ -0,0 +1,465 @@
+//! Module `render.rs`
+//!
+//! Ce module regroupe les fonctions d'affichage du monde : arrière-plan, astéroïdes, missiles,
+//! soucoupes, vaisseau et informations de jeu.
+
+use crate::asteroid::Asteroid;
+use crate::gravitywell::GravityWell;
+use crate::missile::Missile;
+use crate::powerup::{PowerUp, PowerUpKind};
+use crate::saucer::Saucer;
+use crate::spaceship::Spaceship;
+use crate::stellarobject::StellarObject;
+use crate::weapon::Beam;
+use crate::world::World;
+use macroquad::prelude::*;
+use std::f32::consts::PI;
+
+/// Dessine l'arrière-plan du jeu.
+///
+/// - `texture`: Texture utilisée pour l'arrière-plan.
+/// - `opacity`: Opacité appliquée à la texture.
+pub fn draw_background(texture: &Texture2D, opacity: f32) {
+    draw_texture_ex(
+        texture,
+        0.0,
+        0.0,
+        Color::new(1.0, 1.0, 1.0, opacity),
+        DrawTextureParams {
+            dest_size: Some(vec2(screen_width(), screen_height())),
+            ..Default::default()
+        },
+    );
+}
+
+/// Calcule les positions auxquelles dessiner un objet dans l'arène torique : sa position réelle,
+/// plus une copie fantôme de l'autre côté de chaque bord qu'il chevauche.
+///
+/// - `position`: Position de l'objet.
+/// - `extent`: Demi-taille de ce qui est dessiné autour de l'objet.
+/// - `bounds`: Dimensions de l'arène (largeur, hauteur).
+fn wrapped_positions(position: Vec2, extent: f32, bounds: Vec2) -> Vec<Vec2> {
+    let shift = |coord: f32, max: f32| {
+        if coord - extent < 0.0 {
+            max
+        } else if coord + extent > max {
+            -max
+        } else {
+            0.0
+        }
+    };
+    let offset = vec2(shift(position.x, bounds.x), shift(position.y, bounds.y));
+
+    let mut positions = vec![position];
+    if offset.x != 0.0 {
+        positions.push(position + vec2(offset.x, 0.0));
+    }
+    if offset.y != 0.0 {
+        positions.push(position + vec2(0.0, offset.y));
+    }
+    if offset.x != 0.0 && offset.y != 0.0 {
+        positions.push(position + offset);
+    }
+    positions
+}
+
+/// Dessine un astéroïde à sa position interpolée avec la texture donnée.
+///
+/// - `asteroid`: L'astéroïde à dessiner.
+/// - `texture`: Texture de l'astéroïde.
+/// - `alpha`: Fraction du pas de simulation écoulée, utilisée pour l'interpolation.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_asteroid(asteroid: &Asteroid, texture: &Texture2D, alpha: f32, bounds: Vec2) {
+    let radius = asteroid.radius();
+    for position in wrapped_positions(asteroid.interpolated_position(alpha), radius, bounds) {
+        draw_texture_ex(
+            texture,
+            position.x - radius,
+            position.y - radius,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(radius * 2.0, radius * 2.0)),
+                ..Default::default()
+            },
+        );
+    }
+}
+
+/// Dessine un puits de gravité : un disque noir cerclé de violet, entouré d'anneaux qui
+/// s'estompent pour suggérer sa zone d'attraction.
+///
+/// - `well`: Le puits à dessiner.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_well(well: &GravityWell, bounds: Vec2) {
+    let horizon = well.radius();
+    for position in wrapped_positions(well.position(), horizon * 4.0, bounds) {
+        for ring in 1..4 {
+            let color = Color::new(0.6, 0.2, 0.9, 0.4 / ring as f32);
+            draw_circle_lines(
+                position.x,
+                position.y,
+                horizon * (1.0 + ring as f32),
+                2.0,
+                color,
+            );
+        }
+        draw_circle(position.x, position.y, horizon, BLACK);
+        draw_circle_lines(position.x, position.y, horizon, 3.0, VIOLET);
+    }
+}
+
+/// Dessine un missile, représenté par un petit cercle rempli de couleur rouge, ou orange pour un
+/// missile guidé.
+///
+/// - `missile`: Le missile à dessiner.
+/// - `alpha`: Fraction du pas de simulation écoulée, utilisée pour l'interpolation.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_missile(missile: &Missile, alpha: f32, bounds: Vec2) {
+    let radius = missile.radius();
+    let color = if missile.is_homing() { ORANGE } else { RED };
+    for position in wrapped_positions(missile.interpolated_position(alpha), radius, bounds) {
+        draw_circle(position.x, position.y, radius, color);
+    }
+}
+
+/// Dessine un projectile ennemi, représenté par un petit cercle rempli de couleur vert clair.
+///
+/// - `missile`: Le projectile à dessiner.
+/// - `alpha`: Fraction du pas de simulation écoulée, utilisée pour l'interpolation.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_enemy_missile(missile: &Missile, alpha: f32, bounds: Vec2) {
+    let radius = missile.radius();
+    for position in wrapped_positions(missile.interpolated_position(alpha), radius, bounds) {
+        draw_circle(position.x, position.y, radius, LIME);
+    }
+}
+
+/// Dessine une soucoupe : une coque elliptique surmontée d'un dôme.
+///
+/// - `saucer`: La soucoupe à dessiner.
+/// - `alpha`: Fraction du pas de simulation écoulée, utilisée pour l'interpolation.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_saucer(saucer: &Saucer, alpha: f32, bounds: Vec2) {
+    let radius = saucer.radius();
+    for position in wrapped_positions(saucer.interpolated_position(alpha), radius, bounds) {
+        let dome = position - vec2(0.0, radius * 0.3);
+        draw_circle(dome.x, dome.y, radius * 0.45, SKYBLUE);
+        draw_ellipse(position.x, position.y, radius, radius * 0.4, 0.0, GRAY);
+        draw_ellipse_lines(
+            position.x,
+            position.y,
+            radius,
+            radius * 0.4,
+            0.0,
+            2.0,
+            LIGHTGRAY,
+        );
+    }
+}
+
+/// Dessine l'onde de choc du bouclier : un cercle bleu qui s'étend autour du vaisseau.
+///
+/// - `spaceship`: Le vaisseau à l'origine de l'onde.
+/// - `radius`: Rayon actuel de l'onde.
+/// - `alpha`: Fraction du pas de simulation écoulée, utilisée pour l'interpolation.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_shockwave(spaceship: &Spaceship, radius: f32, alpha: f32, bounds: Vec2) {
+    for position in wrapped_positions(spaceship.interpolated_position(alpha), radius, bounds) {
+        draw_circle_lines(position.x, position.y, radius, 4.0, SKYBLUE);
+    }
+}
+
+/// Dessine l'explosion du vaisseau détruit : des débris qui s'éloignent de l'épave en
+/// s'estompant.
+///
+/// - `position`: Position de l'épave.
+/// - `progress`: Avancement de l'explosion, entre 0 et 1.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_explosion(position: Vec2, progress: f32, bounds: Vec2) {
+    let distance = 80.0 * progress;
+    let color = Color::new(1.0, 0.6, 0.1, 1.0 - progress);
+    for center in wrapped_positions(position, distance + 10.0, bounds) {
+        for piece in 0..12 {
+            let direction = Vec2::from_angle(piece as f32 * PI / 6.0 + progress);
+            let start = center + direction * distance;
+            let end = start + direction * 10.0;
+            draw_line(start.x, start.y, end.x, end.y, 2.0, color);
+        }
+    }
+}
+
+/// Dessine un bonus : un cercle de la couleur de son effet, marqué d'une lettre. Le bonus clignote
+/// pendant ses deux dernières secondes.
+///
+/// - `powerup`: Le bonus à dessiner.
+/// - `alpha`: Fraction du pas de simulation écoulée, utilisée pour l'interpolation.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_powerup(powerup: &PowerUp, alpha: f32, bounds: Vec2) {
+    if powerup.lifetime() < 2.0 && (powerup.lifetime() * 8.0) as i32 % 2 == 0 {
+        return;
+    }
+    let (color, letter) = match powerup.kind() {
+        PowerUpKind::ShieldRecharge => (SKYBLUE, "B"),
+        PowerUpKind::WeaponUpgrade => (ORANGE, "A"),
+        PowerUpKind::Invincibility => (YELLOW, "I"),
+        PowerUpKind::ScoreMultiplier => (GREEN, "x2"),
+        PowerUpKind::SlowTime => (VIOLET, "R"),
+    };
+    let radius = powerup.radius();
+    let size = measure_text(letter, None, 24, 1.0);
+    for position in wrapped_positions(powerup.interpolated_position(alpha), radius, bounds) {
+        draw_circle(position.x, position.y, radius, color);
+        draw_text(
+            letter,
+            position.x - size.width / 2.0,
+            position.y + size.offset_y / 2.0,
+            24.0,
+            BLACK,
+        );
+    }
+}
+
+/// Dessine un rayon laser, qui s'estompe jusqu'à disparaître.
+///
+/// - `beam`: Le rayon à dessiner.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_beam(beam: &Beam, bounds: Vec2) {
+    let color = Color::new(0.3, 1.0, 1.0, beam.fade());
+    let length = beam.origin().distance(beam.end());
+    for origin in wrapped_positions(beam.origin(), length, bounds) {
+        let end = origin + (beam.end() - beam.origin());
+        draw_line(origin.x, origin.y, end.x, end.y, 4.0, color);
+    }
+}
+
+/// Dessine le vaisseau et, si applicable, son bouclier à sa position actuelle.
+///
+/// - `spaceship`: Le vaisseau à dessiner.
+/// - `texture_ship`: Texture pour le vaisseau.
+/// - `texture_shield`: Texture pour le bouclier.
+/// - `alpha`: Fraction du pas de simulation écoulée, utilisée pour l'interpolation.
+/// - `bounds`: Dimensions de l'arène, pour dessiner la partie qui dépasse d'un bord.
+fn draw_spaceship(
+    spaceship: &Spaceship,
+    texture_ship: &Texture2D,
+    texture_shield: &Texture2D,
+    alpha: f32,
+    bounds: Vec2,
+) {
+    let radius = spaceship.radius();
+    for position in wrapped_positions(spaceship.interpolated_position(alpha), radius * 1.5, bounds)
+    {
+        draw_spaceship_at(spaceship, texture_ship, texture_shield, position);
+    }
+}
+
+/// Dessine le vaisseau et son bouclier à une position donnée.
+fn draw_spaceship_at(
+    spaceship: &Spaceship,
+    texture_ship: &Texture2D,
+    texture_shield: &Texture2D,
+    position: Vec2,
+) {
+    let radius = spaceship.radius();
+
+    // Dessin du vaisseau
+    draw_texture_ex(
+        texture_ship,
+        position.x - radius,
+        position.y - radius,
+        WHITE,
+        DrawTextureParams {
+            dest_size: Some(vec2(radius * 2.0, radius * 2.0)),
+            rotation: spaceship.angle() + PI / 2.0,
+            ..Default::default()
+        },
+    );
+
+    // Dessin du bouclier s'il est actif, d'autant plus opaque qu'il est chargé
+    if spaceship.shield > 0.0 {
+        draw_texture_ex(
+            texture_shield,
+            position.x - radius * 1.5,
+            position.y - radius * 1.5,
+            Color::new(1.0, 1.0, 1.0, spaceship.shield / Spaceship::MAX_SHIELD),
+            DrawTextureParams {
+                dest_size: Some(vec2(radius * 3.0, radius * 3.0)),
+                rotation: spaceship.angle() + PI / 2.0,
+                ..Default::default()
+            },
+        );
+    }
+
+    // Cercle jaune représentant l'invincibilité du vaisseau
+    if spaceship.invincible {
+        draw_circle_lines(position.x, position.y, radius * 1.3, 3.0, YELLOW);
+    }
+}
+
+/// Textures nécessaires à l'affichage du jeu.
+pub struct Textures {
+    pub background: Texture2D,
+    pub spaceship: Texture2D,
+    pub shield: Texture2D,
+    pub asteroid: Texture2D,
+}
+
+impl Textures {
+    /// Charge toutes les textures du jeu.
+    pub async fn load() -> Self {
+        Self {
+            background: load_texture("src/space_bg.png").await.unwrap(),
+            spaceship: load_texture("src/spaceship.png").await.unwrap(),
+            shield: load_texture("src/shield.png").await.unwrap(),
+            asteroid: load_texture("src/asteroid.png").await.unwrap(),
+        }
+    }
+}
+
+/// Dessine les objets du jeu (fond, puits de gravité, astéroïdes, missiles, vaisseau, score).
+///
+/// - `world`: Le monde à dessiner.
+/// - `textures`: Textures du jeu.
+/// - `alpha`: Fraction du pas de simulation écoulée, utilisée pour l'interpolation.
+pub fn draw_game(world: &World, textures: &Textures, alpha: f32) {
+    draw_background(&textures.background, 1.0);
+
+    let bounds = world.bounds();
+    for well in world.wells() {
+        draw_well(well, bounds);
+    }
+    for asteroid in world.asteroids() {
+        draw_asteroid(asteroid, &textures.asteroid, alpha, bounds);
+    }
+    for missile in world.missiles() {
+        draw_missile(missile, alpha, bounds);
+    }
+    for beam in world.beams() {
+        draw_beam(beam, bounds);
+    }
+    for saucer in world.saucers() {
+        draw_saucer(saucer, alpha, bounds);
+    }
+    for missile in world.enemy_missiles() {
+        draw_enemy_missile(missile, alpha, bounds);
+    }
+    for powerup in world.powerups() {
+        draw_powerup(powerup, alpha, bounds);
+    }
+    if let Some(radius) = world.shockwave_radius() {
+        draw_shockwave(world.spaceship(), radius, alpha, bounds);
+    }
+    if let Some(progress) = world.explosion_progress() {
+        draw_explosion(world.spaceship().position(), progress, bounds);
+    }
+    // Le vaisseau clignote pendant la période de grâce qui suit sa réapparition.
+    let blink_off = (world.grace_time() * 8.0) as i32 % 2 == 1;
+    if !world.is_respawning() && !blink_off {
+        draw_spaceship(
+            world.spaceship(),
+            &textures.spaceship,
+            &textures.shield,
+            alpha,
+            bounds,
+        );
+    }
+
+    // Affiche les informations sur l'écran
+    let text_shield = format!("Vies : {}", world.lives());
+    let text_score = format!("Score : {}", world.score());
+    let text_level = format!("Vague : {}", world.level());
+    let text_weapon = format!("Arme : {}", world.spaceship().weapon().name());
+
+    draw_text(
+        &text_shield,
+        screen_width() / 2.0 - 200.0,
+        screen_height() * 0.05,
+        50.0,
+        GREEN,
+    );
+    draw_text(
+        &text_score,
+        screen_width() / 2.0 + 100.0,
+        screen_height() * 0.05,
+        50.0,
+        GREEN,
+    );
+    draw_text(
+        &text_level,
+        screen_width() / 2.0 - 500.0,
+        screen_height() * 0.05,
+        50.0,
+        GREEN,
+    );
+    draw_text(
+        &text_weapon,
+        screen_width() / 2.0 + 400.0,
+        screen_height() * 0.05,
+        50.0,
+        GREEN,
+    );
+
+    // Effets temporaires des bonus encore actifs
+    let mut effects = Vec::new();
+    if world.combo_multiplier() > 1 {
+        effects.push(format!("Combo x{}", world.combo_multiplier()));
+    }
+    if world.score_multiplier_time() > 0.0 {
+        effects.push(format!(
+            "Score x2 : {:.0} s",
+            world.score_multiplier_time().ceil()
+        ));
+    }
+    if world.slow_time() > 0.0 {
+        effects.push(format!("Ralenti : {:.0} s", world.slow_time().ceil()));
+    }
+    if world.hyperspace_cooldown() > 0.0 {
+        effects.push(format!(
+            "Hyperespace : {:.0} s",
+            world.hyperspace_cooldown().ceil()
+        ));
+    }
+    draw_text(
+        effects.join("   ").as_str(),
+        screen_width() / 2.0 - 500.0,
+        screen_height() * 0.05 + 60.0,
+        40.0,
+        YELLOW,
+    );
+
+    // Jauge d'énergie du bouclier
+    let shield = world.spaceship().shield / Spaceship::MAX_SHIELD;
+    draw_rectangle_lines(
+        screen_width() / 2.0 - 200.0,
+        screen_height() * 0.05 + 10.0,
+        200.0,
+        8.0,
+        2.0,
+        SKYBLUE,
+    );
+    draw_rectangle(
+        screen_width() / 2.0 - 200.0,
+        screen_height() * 0.05 + 10.0,
+        200.0 * shield,
+        8.0,
+        if shield < 0.25 { RED } else { SKYBLUE },
+    );
+
+    // Jauge de rechargement de l'arme, pleine lorsqu'elle peut tirer
+    let gauge_width = 200.0;
+    draw_rectangle_lines(
+        screen_width() / 2.0 + 400.0,
+        screen_height() * 0.05 + 10.0,
+        gauge_width,
+        8.0,
+        2.0,
+        GREEN,
+    );
+    draw_rectangle(
+        screen_width() / 2.0 + 400.0,
+        screen_height() * 0.05 + 10.0,
+        gauge_width * world.weapon_readiness(),
+        8.0,
+        GREEN,
+    );
+}
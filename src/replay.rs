@@ -0,0 +1,410 @@
+//! Module `replay.rs`
+//!
+//! Ce module définit la structure `Replay`, qui enregistre les commandes du joueur à chaque pas de
+//! simulation ainsi que les paramètres de création du monde. Comme la simulation est déterministe,
+//! rejouer ces commandes reproduit la partie à l'identique.
+
+use crate::gameplay::GameplayConfig;
+use crate::wave::WaveConfig;
+use crate::world::{PlayerInput, World, WorldStatus};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// Signature placée au début de chaque fichier de replay.
+const MAGIC: &[u8; 4] = b"ASTR";
+
+/// Version du format de fichier.
+const VERSION: u8 = 1;
+
+/// Largeur et hauteur maximales de l'arène d'un replay, en pixels.
+const MAX_ARENA_SIDE: f32 = 100_000.0;
+
+/// Nombre maximal de pas de simulation d'un replay (24 heures de jeu), au-delà duquel le fichier
+/// est considéré comme corrompu.
+const MAX_STEPS: u64 = 24 * 60 * 60 * 60;
+
+/// Structure `Replay` qui représente l'enregistrement d'une partie.
+///
+/// Le fichier produit contient un en-tête (graine, dimensions de l'arène, progression des vagues
+/// et réglages de jeu, chaque paramètre étant enregistré avec son nom) suivi des commandes
+/// compressées par plages : chaque plage est un mot de commandes (deux octets), le cap visé et la
+/// poussée analogique s'ils sont utilisés, et le nombre de pas consécutifs pendant lesquels elles
+/// sont restées identiques.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay {
+    seed: u64,                // Graine du générateur aléatoire
+    waves: WaveConfig,        // Progression des vagues
+    gameplay: GameplayConfig, // Réglages de jeu communs à toutes les vagues
+    width: f32,               // Largeur de l'arène
+    height: f32,              // Hauteur de l'arène
+    inputs: Vec<PlayerInput>, // Commandes du joueur, une par pas de simulation
+}
+
+impl Replay {
+    /// Crée un enregistrement vide pour une partie aux paramètres donnés.
+    ///
+    /// # Arguments
+    /// * `width` - Largeur de l'arène.
+    /// * `height` - Hauteur de l'arène.
+    /// * `waves` - Progression des vagues d'astéroïdes.
+    /// * `gameplay` - Réglages de jeu communs à toutes les vagues.
+    /// * `seed` - Graine du générateur aléatoire de la partie.
+    pub fn new(
+        width: f32,
+        height: f32,
+        waves: WaveConfig,
+        gameplay: GameplayConfig,
+        seed: u64,
+    ) -> Self {
+        Self {
+            seed,
+            waves,
+            gameplay,
+            width,
+            height,
+            inputs: Vec::new(),
+        }
+    }
+
+    /// Crée un enregistrement vide reprenant les paramètres de création de ce replay.
+    pub fn restart(&self) -> Self {
+        Self::new(
+            self.width,
+            self.height,
+            self.waves,
+            self.gameplay,
+            self.seed,
+        )
+    }
+
+    /// Crée le monde dans son état initial, tel qu'il était au début de la partie enregistrée.
+    pub fn world(&self) -> World {
+        World::new(
+            self.width,
+            self.height,
+            self.waves,
+            self.gameplay,
+            self.seed,
+        )
+    }
+
+    /// Retourne les commandes enregistrées, une par pas de simulation.
+    pub fn inputs(&self) -> &[PlayerInput] {
+        &self.inputs
+    }
+
+    /// Ajoute les commandes d'un pas de simulation à l'enregistrement.
+    pub fn record(&mut self, input: PlayerInput) {
+        self.inputs.push(input);
+    }
+
+    /// Rejoue toute la partie enregistrée sans affichage.
+    ///
+    /// # Retourne
+    /// * `(World, WorldStatus)` - Le monde à la fin du replay et l'état de la partie au dernier pas
+    ///   joué.
+    pub fn play(&self) -> (World, WorldStatus) {
+        let mut world = self.world();
+        let mut status = WorldStatus::Running;
+        for input in &self.inputs {
+            status = world.step(input);
+            if status == WorldStatus::Defeat {
+                break;
+            }
+        }
+        (world, status)
+    }
+
+    /// Enregistre le replay dans un fichier.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Charge un replay depuis un fichier.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read_from(&mut BufReader::new(File::open(path)?))
+    }
+
+    /// Écrit le replay au format binaire.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&self.seed.to_le_bytes())?;
+        writer.write_all(&self.width.to_le_bytes())?;
+        writer.write_all(&self.height.to_le_bytes())?;
+        write_fields(writer, &self.waves.fields())?;
+        write_fields(writer, &self.gameplay.fields())?;
+
+        // Compression par plages des commandes identiques consécutives.
+        let mut runs: Vec<(PlayerInput, u32)> = Vec::new();
+        for input in &self.inputs {
+            match runs.last_mut() {
+                Some((last, count)) if last == input && *count < u32::MAX => *count += 1,
+                _ => runs.push((*input, 1)),
+            }
+        }
+
+        writer.write_all(&(runs.len() as u32).to_le_bytes())?;
+        for (input, count) in runs {
+            write_input(writer, &input)?;
+            writer.write_all(&count.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Lit un replay au format binaire.
+    pub fn read_from(reader: &mut impl Read) -> io::Result<Self> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("fichier de replay invalide"));
+        }
+        if read_array::<1>(reader)?[0] != VERSION {
+            return Err(invalid_data("version de replay non supportée"));
+        }
+
+        let seed = u64::from_le_bytes(read_array(reader)?);
+        let width = f32::from_le_bytes(read_array(reader)?);
+        let height = f32::from_le_bytes(read_array(reader)?);
+        if ![width, height]
+            .iter()
+            .all(|side| (1.0..=MAX_ARENA_SIDE).contains(side))
+        {
+            return Err(invalid_data("dimensions de l'arène invalides"));
+        }
+        let mut waves = WaveConfig::default();
+        read_fields(reader, |name, value| waves.set_field(name, value))?;
+        let mut gameplay = GameplayConfig::default();
+        read_fields(reader, |name, value| gameplay.set_field(name, value))?;
+
+        let nbr_runs = u32::from_le_bytes(read_array(reader)?);
+        let mut inputs = Vec::new();
+        for _ in 0..nbr_runs {
+            let input = read_input(reader)?;
+            let count = u32::from_le_bytes(read_array(reader)?);
+            if (inputs.len() as u64 + count as u64) > MAX_STEPS {
+                return Err(invalid_data("replay trop long"));
+            }
+            inputs.extend(std::iter::repeat_n(input, count as usize));
+        }
+
+        Ok(Self {
+            seed,
+            waves,
+            gameplay,
+            width,
+            height,
+            inputs,
+        })
+    }
+}
+
+/// Écrit des paramètres nommés : leur nombre, puis pour chacun la longueur de son nom, son nom
+/// et sa valeur.
+fn write_fields(writer: &mut impl Write, fields: &[(&str, f64)]) -> io::Result<()> {
+    writer.write_all(&(fields.len() as u16).to_le_bytes())?;
+    for (name, value) in fields {
+        writer.write_all(&[name.len() as u8])?;
+        writer.write_all(name.as_bytes())?;
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// Lit des paramètres écrits par `write_fields`.
+///
+/// Les paramètres absents gardent leur valeur par défaut. Un nom inconnu ou une valeur hors de sa
+/// plage (infinie ou indéterminée comprises) rend le fichier invalide : la partie ne pourrait pas
+/// être reproduite à l'identique.
+///
+/// # Arguments
+/// * `reader` - La source des données.
+/// * `set` - Modifie un paramètre à partir de son nom, et retourne `false` si le nom est inconnu
+///   ou la valeur invalide.
+fn read_fields(reader: &mut impl Read, mut set: impl FnMut(&str, f64) -> bool) -> io::Result<()> {
+    let count = u16::from_le_bytes(read_array(reader)?);
+    for _ in 0..count {
+        let mut name = vec![0u8; read_array::<1>(reader)?[0] as usize];
+        reader.read_exact(&mut name)?;
+        let value = f64::from_le_bytes(read_array(reader)?);
+        let known = std::str::from_utf8(&name).is_ok_and(|name| set(name, value));
+        if !known {
+            return Err(invalid_data("paramètre de replay inconnu ou invalide"));
+        }
+    }
+    Ok(())
+}
+
+/// Écrit les commandes d'un pas : deux octets (un bit par commande, un bit pour chaque commande
+/// analogique utilisée et un bit pour la gâchette maintenue), suivis du cap visé (deux octets) et
+/// de la poussée analogique (un octet) s'ils sont utilisés.
+fn write_input(writer: &mut impl Write, input: &PlayerInput) -> io::Result<()> {
+    let bits = (input.turn_left as u16)
+        | (input.turn_right as u16) << 1
+        | (input.thrust as u16) << 2
+        | (input.reverse as u16) << 3
+        | (input.fire as u16) << 4
+        | (input.switch_weapon as u16) << 5
+        | (input.hyperspace as u16) << 6
+        | (input.shield_bash as u16) << 7
+        | (input.aim.is_some() as u16) << 8
+        | ((input.throttle > 0) as u16) << 9
+        | (input.fire_held as u16) << 10;
+    writer.write_all(&bits.to_le_bytes())?;
+    if let Some(aim) = input.aim {
+        writer.write_all(&aim.to_le_bytes())?;
+    }
+    if input.throttle > 0 {
+        writer.write_all(&[input.throttle])?;
+    }
+    Ok(())
+}
+
+/// Lit les commandes d'un pas écrites par `write_input`.
+fn read_input(reader: &mut impl Read) -> io::Result<PlayerInput> {
+    let bits = u16::from_le_bytes(read_array(reader)?);
+    if bits >> 11 != 0 {
+        return Err(invalid_data("commande de replay inconnue"));
+    }
+    let aim = if bits & 1 << 8 != 0 {
+        Some(u16::from_le_bytes(read_array(reader)?))
+    } else {
+        None
+    };
+    let throttle = if bits & 1 << 9 != 0 {
+        read_array::<1>(reader)?[0]
+    } else {
+        0
+    };
+    Ok(PlayerInput {
+        turn_left: bits & 1 != 0,
+        turn_right: bits & 1 << 1 != 0,
+        thrust: bits & 1 << 2 != 0,
+        reverse: bits & 1 << 3 != 0,
+        fire: bits & 1 << 4 != 0,
+        fire_held: bits & 1 << 10 != 0,
+        switch_weapon: bits & 1 << 5 != 0,
+        hyperspace: bits & 1 << 6 != 0,
+        shield_bash: bits & 1 << 7 != 0,
+        aim,
+        throttle,
+    })
+}
+
+/// Lit exactement `N` octets.
+fn read_array<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut buffer = [0u8; N];
+    reader.read_exact(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Construit une erreur de données invalides.
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Enregistre une partie scriptée de `steps` pas.
+    fn scripted_replay(steps: usize) -> Replay {
+        let mut replay = Replay::new(
+            1280.0,
+            720.0,
+            WaveConfig::default(),
+            GameplayConfig::default(),
+            2024,
+        );
+        for step in 0..steps {
+            let mut input = PlayerInput {
+                turn_left: step % 240 < 60,
+                thrust: step % 120 < 30,
+                fire: step % 15 == 0,
+                fire_held: step % 300 < 90,
+                switch_weapon: step % 300 == 0,
+                hyperspace: step % 400 == 200,
+                shield_bash: step == 900,
+                ..Default::default()
+            };
+            if step % 600 >= 450 {
+                input.set_aim(step as f32 / 100.0);
+                input.set_throttle(0.5);
+            }
+            replay.record(input);
+        }
+        replay
+    }
+
+    #[test]
+    fn test_replay_round_trip() {
+        let mut replay = scripted_replay(1000);
+        replay.waves.max_count = 12;
+        replay.gameplay.well_horizon = 30.0;
+        let mut bytes = Vec::new();
+        replay.write_to(&mut bytes).unwrap();
+
+        let loaded = Replay::read_from(&mut bytes.as_slice()).unwrap();
+        assert_eq!(loaded, replay);
+    }
+
+    #[test]
+    fn test_replay_reproduces_game() {
+        let replay = scripted_replay(1800);
+        let mut bytes = Vec::new();
+        replay.write_to(&mut bytes).unwrap();
+        let loaded = Replay::read_from(&mut bytes.as_slice()).unwrap();
+
+        let (world1, status1) = replay.play();
+        let (world2, status2) = loaded.play();
+        assert_eq!(status1, status2);
+        assert_eq!(world1.score(), world2.score());
+        assert_eq!(world1.asteroids().len(), world2.asteroids().len());
+
+        // Valeurs de référence : toute perte de déterminisme de la simulation les modifie.
+        assert_eq!(status1, WorldStatus::Running);
+        assert_eq!(
+            (world1.score(), world1.level(), world1.asteroids().len()),
+            (2105, 1, 8)
+        );
+    }
+
+    #[test]
+    fn test_replay_rejects_corrupted_file() {
+        assert!(Replay::read_from(&mut b"NOPE".as_slice()).is_err());
+
+        let mut bytes = Vec::new();
+        scripted_replay(10).write_to(&mut bytes).unwrap();
+        bytes.truncate(bytes.len() - 1);
+        assert!(Replay::read_from(&mut bytes.as_slice()).is_err());
+
+        // Une plage démesurée est refusée au lieu d'épuiser la mémoire.
+        let mut bytes = Vec::new();
+        Replay::new(
+            800.0,
+            600.0,
+            WaveConfig::default(),
+            GameplayConfig::default(),
+            1,
+        )
+        .write_to(&mut bytes)
+        .unwrap();
+        bytes.truncate(bytes.len() - 4);
+        bytes.extend(1u32.to_le_bytes());
+        write_input(&mut bytes, &PlayerInput::default()).unwrap();
+        bytes.extend(u32::MAX.to_le_bytes());
+        let error = Replay::read_from(&mut bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        // Un paramètre indéterminé est refusé au lieu de faire échouer la partie rejouée.
+        let mut replay = scripted_replay(10);
+        replay.gameplay.hyperspace_failure = f32::NAN;
+        let mut bytes = Vec::new();
+        replay.write_to(&mut bytes).unwrap();
+        let error = Replay::read_from(&mut bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,265 @@
+//! Module `saucer.rs`
+//!
+//! Ce module définit la structure `Saucer`, une soucoupe volante ennemie. Elle entre par un bord
+//! de l'arène, la traverse en changeant régulièrement de cap et tire sur le vaisseau.
+
+use crate::arena;
+use crate::missile::Missile;
+use crate::stellarobject::StellarObject;
+use ::rand::Rng;
+use macroquad::math::{vec2, Vec2};
+use std::f32::consts::PI;
+
+/// Tailles de soucoupes : la grande tire au hasard, la petite vise le vaisseau.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaucerSize {
+    Large, // Grande soucoupe, lente et imprécise
+    Small, // Petite soucoupe, rapide et précise
+}
+
+impl SaucerSize {
+    /// Retourne le rayon d'une soucoupe de cette taille.
+    pub fn radius(&self) -> f32 {
+        match self {
+            SaucerSize::Large => 25.0,
+            SaucerSize::Small => 12.0,
+        }
+    }
+
+    /// Retourne les points rapportés par la destruction d'une soucoupe de cette taille : comme dans
+    /// le jeu d'arcade, une soucoupe rapporte bien plus qu'un astéroïde.
+    pub fn points(&self) -> i32 {
+        match self {
+            SaucerSize::Large => 200,
+            SaucerSize::Small => 1000,
+        }
+    }
+
+    /// Retourne la vitesse d'une soucoupe de cette taille, en pixels par seconde.
+    fn speed(&self) -> f32 {
+        match self {
+            SaucerSize::Large => 80.0,
+            SaucerSize::Small => 120.0,
+        }
+    }
+
+    /// Retourne le délai entre deux tirs d'une soucoupe de cette taille, en secondes.
+    fn fire_interval(&self) -> f32 {
+        match self {
+            SaucerSize::Large => 1.5,
+            SaucerSize::Small => 1.0,
+        }
+    }
+}
+
+/// Structure `Saucer` représentant une soucoupe volante ennemie.
+///
+/// La soucoupe avance horizontalement et change de cap vertical toutes les une à deux secondes.
+/// Elle quitte l'arène après l'avoir traversée une fois dans sa largeur.
+pub struct Saucer {
+    size: SaucerSize,        // Taille de la soucoupe
+    position: Vec2,          // Position actuelle de la soucoupe
+    previous_position: Vec2, // Position de la soucoupe avant le dernier pas
+    velocity: Vec2,          // Vitesse de la soucoupe, en pixels par seconde
+    travel_left: f32,        // Distance horizontale restante avant de quitter l'arène
+    turn_timer: f32,         // Délai avant le prochain changement de cap, en secondes
+    fire_timer: f32,         // Délai avant le prochain tir, en secondes
+}
+
+impl Saucer {
+    // Vitesse des projectiles ennemis, en pixels par seconde
+    pub const BULLET_SPEED: f32 = 200.0;
+    // Durée de vie des projectiles ennemis, en secondes
+    pub const BULLET_LIFETIME: f32 = 1.5;
+
+    /// Fait entrer une soucoupe par le bord gauche ou droit de l'arène, à une hauteur aléatoire.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - La taille de la soucoupe.
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    /// * `rng` - Le générateur aléatoire de la partie.
+    pub fn enter(size: SaucerSize, bounds: Vec2, rng: &mut impl Rng) -> Self {
+        let from_left = rng.gen_bool(0.5);
+        let position = vec2(
+            if from_left { 0.0 } else { bounds.x - 1.0 },
+            rng.gen_range(0.0..bounds.y),
+        );
+        let direction = if from_left { 1.0 } else { -1.0 };
+        Self {
+            size,
+            position,
+            previous_position: position,
+            velocity: vec2(direction * size.speed(), 0.0),
+            travel_left: bounds.x,
+            turn_timer: rng.gen_range(1.0..2.0),
+            fire_timer: size.fire_interval(),
+        }
+    }
+
+    /// Crée une soucoupe immobile à une position donnée, qui ne tire pas avant son délai.
+    #[cfg(test)]
+    pub fn new_at(size: SaucerSize, position: Vec2) -> Self {
+        Self {
+            size,
+            position,
+            previous_position: position,
+            velocity: Vec2::ZERO,
+            travel_left: f32::INFINITY,
+            turn_timer: f32::INFINITY,
+            fire_timer: size.fire_interval(),
+        }
+    }
+
+    /// Retourne la taille de la soucoupe.
+    pub fn size(&self) -> SaucerSize {
+        self.size
+    }
+
+    /// Retourne la vitesse de la soucoupe, en pixels par seconde.
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Indique si la soucoupe a fini de traverser l'arène et doit disparaître.
+    pub fn has_left(&self) -> bool {
+        self.travel_left <= 0.0
+    }
+
+    /// Change éventuellement de cap : la soucoupe garde son sens horizontal et choisit au hasard
+    /// de monter, de descendre ou d'aller tout droit.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - La durée du pas, en secondes.
+    /// * `rng` - Le générateur aléatoire de la partie.
+    pub fn steer(&mut self, dt: f32, rng: &mut impl Rng) {
+        self.turn_timer -= dt;
+        if self.turn_timer > 0.0 {
+            return;
+        }
+        self.turn_timer = rng.gen_range(1.0..2.0);
+        let speed = self.size.speed();
+        let vertical = rng.gen_range(-1..=1) as f32 * speed * 0.6;
+        self.velocity = vec2(self.velocity.x.signum() * speed, vertical);
+    }
+
+    /// Tire éventuellement un projectile vers le vaisseau.
+    ///
+    /// La direction du tir est écartée de la direction du vaisseau d'un angle aléatoire, d'autant
+    /// plus petit que la soucoupe est précise. La grande soucoupe est deux fois moins précise que
+    /// la petite.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - La position du vaisseau.
+    /// * `accuracy` - La précision de la petite soucoupe, entre 0 (tir au hasard) et 1 (tir parfait).
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    /// * `dt` - La durée du pas, en secondes.
+    /// * `rng` - Le générateur aléatoire de la partie.
+    ///
+    /// # Retourne
+    ///
+    /// * `Option<Missile>` - Le projectile tiré, ou `None` si la soucoupe recharge.
+    pub fn fire(
+        &mut self,
+        target: Vec2,
+        accuracy: f32,
+        bounds: Vec2,
+        dt: f32,
+        rng: &mut impl Rng,
+    ) -> Option<Missile> {
+        self.fire_timer -= dt;
+        if self.fire_timer > 0.0 {
+            return None;
+        }
+        self.fire_timer = self.size.fire_interval();
+
+        let accuracy = match self.size {
+            SaucerSize::Large => accuracy * 0.5,
+            SaucerSize::Small => accuracy,
+        }
+        .clamp(0.0, 1.0);
+        let aim = arena::wrapped_delta(self.position, target, bounds).to_angle();
+        let max_error = (1.0 - accuracy) * PI;
+        let angle = aim + rng.gen_range(-max_error..=max_error);
+        Some(Missile::new(
+            self.position,
+            Vec2::from_angle(angle) * Self::BULLET_SPEED,
+            3.0,
+            Self::BULLET_LIFETIME,
+            false,
+        ))
+    }
+}
+
+impl StellarObject for Saucer {
+    /// Retourne la position actuelle de la soucoupe.
+    fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Retourne la position de la soucoupe avant le dernier pas.
+    fn previous_position(&self) -> Vec2 {
+        self.previous_position
+    }
+
+    /// Déplace la soucoupe, qui réapparaît du côté opposé lorsqu'elle sort verticalement de
+    /// l'arène, et décompte la distance horizontale qu'il lui reste à parcourir.
+    fn update_position(&mut self, bounds: Vec2, dt: f32) {
+        (self.position, self.previous_position) =
+            arena::move_wrapped(self.position, self.velocity * dt, bounds);
+        self.travel_left -= self.velocity.x.abs() * dt;
+    }
+
+    /// Retourne le rayon de la soucoupe.
+    fn radius(&self) -> f32 {
+        self.size.radius()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::rngs::SmallRng;
+    use ::rand::SeedableRng;
+
+    #[test]
+    fn test_saucer_crosses_the_arena_once() {
+        let bounds = vec2(800.0, 600.0);
+        let mut rng = SmallRng::seed_from_u64(17);
+        let mut saucer = Saucer::enter(SaucerSize::Large, bounds, &mut rng);
+        let dt = 1.0 / 60.0;
+
+        let mut steps: usize = 0;
+        while !saucer.has_left() {
+            saucer.steer(dt, &mut rng);
+            saucer.update_position(bounds, dt);
+            steps += 1;
+        }
+        let expected = (bounds.x / SaucerSize::Large.speed() / dt).round() as usize;
+        assert!(steps.abs_diff(expected) <= 1);
+    }
+
+    #[test]
+    fn test_perfect_accuracy_hits_the_target() {
+        let bounds = vec2(800.0, 600.0);
+        let mut rng = SmallRng::seed_from_u64(18);
+        let mut saucer = Saucer::enter(SaucerSize::Small, bounds, &mut rng);
+        saucer.position = vec2(100.0, 100.0);
+        let target = vec2(300.0, 300.0);
+
+        let bullet = saucer
+            .fire(
+                target,
+                1.0,
+                bounds,
+                SaucerSize::Small.fire_interval(),
+                &mut rng,
+            )
+            .unwrap();
+        let direction = bullet.velocity().normalize();
+        assert!(direction.distance(vec2(1.0, 1.0).normalize()) < 1e-4);
+        assert!(saucer.fire(target, 1.0, bounds, 0.1, &mut rng).is_none());
+    }
+}
@@ -2,44 +2,54 @@
 //!
 //! Ce module définit la structure `Spaceship`.
 
+use crate::arena;
 use crate::stellarobject::StellarObject;
-use macroquad::prelude::*;
+use crate::weapon::Weapon;
+use macroquad::math::{vec2, Vec2};
 use std::f32::consts::PI;
 
 /// Structure `Spaceship` qui représente le vaisseau spatial du jeu.
 ///
-/// Le vaisseau a une position, une vitesse, une orientation, une intensité de pousée, un bouclier, des textures...  Il se déplace selon une certaine vitesse
-/// et il vérifie son positionnement; ce qui signifie que si il dépasse les dimensions de la fenêtre, il
+/// Le vaisseau a une position, une vitesse, une orientation, une intensité de pousée, un bouclier...  Il se déplace selon une certaine vitesse
+/// et il vérifie son positionnement; ce qui signifie que si il dépasse les dimensions de l'arène, il
 /// réapparaît du côté opposé.
 pub struct Spaceship {
-    position: Vec2,            // Position actuelle du vaisseau
-    speed: Vec2,               // Vitesse actuelle du vaisseau
-    angle: f32,                // Orientation du vaisseau en radians
-    thrust: f32,               // Intensité de la poussée
-    radius: f32,               // Rayon du vaisseau
-    pub shield: u32,           // Points de bouclier restants
-    pub invincible: bool,      // Indique si le vaisseau est temporairement invincible
-    texture_ship: Texture2D,   // Texture pour représenter le vaisseau
-    texture_shield: Texture2D, // Texture pour représenter le bouclier
+    position: Vec2,          // Position actuelle du vaisseau
+    previous_position: Vec2, // Position du vaisseau avant le dernier pas
+    speed: Vec2,             // Vitesse actuelle du vaisseau, en pixels par seconde
+    angle: f32,              // Orientation du vaisseau en radians
+    thrust: f32,             // Intensité de la poussée, en pixels par seconde au carré
+    radius: f32,             // Rayon du vaisseau
+    pub shield: f32,         // Énergie du bouclier, entre 0 et `MAX_SHIELD`
+    pub invincible: bool,    // Indique si le vaisseau est temporairement invincible
+    weapon: Weapon,          // Arme sélectionnée
 }
 
 impl Spaceship {
-    /// Crée un nouveau vaisseau spatial à une position centrale avec des textures données.
+    // Vitesse de rotation, en radians par seconde
+    pub const ROTATION_SPEED: f32 = 0.9;
+    // Vitesse maximale, en pixels par seconde
+    pub const MAX_SPEED: f32 = 60.0;
+    // Fraction de la vitesse conservée après une seconde sans poussée (frottements)
+    pub const FRICTION: f32 = 0.94;
+    // Énergie du bouclier d'un vaisseau neuf, ou dont le bouclier vient d'être rechargé
+    pub const MAX_SHIELD: f32 = 100.0;
+
+    /// Crée un nouveau vaisseau spatial à la position donnée.
     ///
     /// # Arguments
-    /// * `texture_ship` - Texture pour le vaisseau.
-    /// * `texture_shield` - Texture pour le bouclier.
-    pub fn new(texture_ship: &Texture2D, texture_shield: &Texture2D) -> Self {
+    /// * `position` - Position initiale du vaisseau (en général le centre de l'arène).
+    pub fn new(position: Vec2) -> Self {
         Self {
-            position: vec2(screen_width() / 2.0, screen_height() / 2.0),
+            position,
+            previous_position: position,
             speed: vec2(0.0, 0.0), // Initialement à l'arrêt
             angle: -PI / 2.0,      // Orienté vers le haut
             thrust: 0.0,           // Pas de poussée par défaut
             radius: 30.0,
-            shield: 2,         // Bouclier initial avec 2 points
-            invincible: false, // Non invincible par défaut
-            texture_ship: texture_ship.clone(),
-            texture_shield: texture_shield.clone(),
+            shield: Self::MAX_SHIELD, // Bouclier initialement chargé
+            invincible: false,        // Non invincible par défaut
+            weapon: Weapon::default(),
         }
     }
 
@@ -48,85 +58,105 @@ impl Spaceship {
         self.angle
     }
 
+    /// Retourne l'arme sélectionnée.
+    pub fn weapon(&self) -> Weapon {
+        self.weapon
+    }
+
+    /// Fait encaisser des dégâts au bouclier.
+    ///
+    /// # Arguments
+    /// * `damage` - L'énergie retirée au bouclier.
+    ///
+    /// # Retourne
+    /// * un booléen - `true` si le bouclier a tenu, `false` s'il n'avait pas assez d'énergie : il
+    ///   est alors vidé et le vaisseau est détruit.
+    pub fn absorb(&mut self, damage: f32) -> bool {
+        if damage <= self.shield {
+            self.shield -= damage;
+            true
+        } else {
+            self.shield = 0.0;
+            false
+        }
+    }
+
+    /// Recharge le bouclier, sans dépasser `MAX_SHIELD`.
+    ///
+    /// # Arguments
+    /// * `energy` - L'énergie ajoutée au bouclier.
+    pub fn recharge(&mut self, energy: f32) {
+        self.shield = (self.shield + energy).min(Self::MAX_SHIELD);
+    }
+
+    /// Sélectionne l'arme suivante.
+    pub fn next_weapon(&mut self) {
+        self.weapon = self.weapon.next();
+    }
+
+    /// Passe à l'arme suivante, sans revenir à la première.
+    pub fn upgrade_weapon(&mut self) {
+        self.weapon = self.weapon.upgrade();
+    }
+
+    /// Retourne la vitesse actuelle du vaisseau, en pixels par seconde.
+    pub fn speed(&self) -> Vec2 {
+        self.speed
+    }
+
+    /// Modifie la vitesse du vaisseau sous l'effet d'une accélération extérieure.
+    ///
+    /// # Arguments
+    /// * `acceleration` - L'accélération subie, en pixels par seconde au carré.
+    /// * `dt` - La durée pendant laquelle elle s'applique, en secondes.
+    pub fn accelerate(&mut self, acceleration: Vec2, dt: f32) {
+        self.speed += acceleration * dt;
+    }
+
+    /// Déplace instantanément le vaisseau, arrêté, à une nouvelle position.
+    ///
+    /// # Arguments
+    /// * `position` - La nouvelle position du vaisseau.
+    pub fn teleport(&mut self, position: Vec2) {
+        self.position = position;
+        self.previous_position = position;
+        self.speed = Vec2::ZERO;
+    }
+
     /// Applique une poussée au vaisseau dans la direction de son angle.
     ///
     /// # Arguments
-    /// * `amount` - Intensité de la poussée à appliquer.
+    /// * `amount` - Intensité de la poussée à appliquer, en pixels par seconde au carré.
     pub fn apply_thrust(&mut self, amount: f32) {
         self.thrust = amount;
     }
 
     /// Tourne le vaisseau dans le sens des aiguilles d'une montre.
-    pub fn turn_right(&mut self) {
-        self.angle += 0.015;
+    ///
+    /// # Arguments
+    /// * `dt` - Durée de la rotation, en secondes.
+    pub fn turn_right(&mut self, dt: f32) {
+        self.angle += Self::ROTATION_SPEED * dt;
     }
 
     /// Tourne le vaisseau dans le sens inverse des aiguilles d'une montre.
-    pub fn turn_left(&mut self) {
-        self.angle -= 0.015;
-    }
-
-    /// Dessine le vaisseau et, si applicable, son bouclier à sa position actuelle.
-    pub fn draw(&self) {
-        // Dessin du vaisseau
-        draw_texture_ex(
-            &self.texture_ship,
-            self.position.x - self.radius,
-            self.position.y - self.radius,
-            WHITE,
-            DrawTextureParams {
-                dest_size: Some(vec2(self.radius * 2.0, self.radius * 2.0)),
-                rotation: self.angle + PI / 2.0,
-                ..Default::default()
-            },
-        );
-
-        // Dessin du bouclier s'il est actif
-        if self.shield > 0 {
-            draw_texture_ex(
-                &self.texture_shield,
-                self.position.x - self.radius * 1.5,
-                self.position.y - self.radius * 1.5,
-                WHITE,
-                DrawTextureParams {
-                    dest_size: Some(vec2(self.radius * 3.0, self.radius * 3.0)),
-                    rotation: self.angle + PI / 2.0,
-                    ..Default::default()
-                },
-            );
-        }
-
-        // Cercle jaune représentant l'invincibilité du vaisseau
-        if self.invincible {
-            draw_circle_lines(
-                self.position.x,
-                self.position.y,
-                self.radius * 1.3,
-                3.0,
-                YELLOW,
-            );
-        }
-    }
-
-    /// Contraint le vaisseau à rester dans les limites de la fenêtre
-    fn bound_pos(mut pos: Vec2) -> Vec2 {
-        pos.x = Self::bound_to(pos.x, screen_width());
-        pos.y = Self::bound_to(pos.y, screen_height());
-        pos
+    ///
+    /// # Arguments
+    /// * `dt` - Durée de la rotation, en secondes.
+    pub fn turn_left(&mut self, dt: f32) {
+        self.angle -= Self::ROTATION_SPEED * dt;
     }
 
-    /// Si le vaisseau dépasse les coordonnées, il réapparaît de l'autre
-    /// côté de la fenêtre
-    /// # Argument
-    /// * `max` - Coordonée maximum.
-    fn bound_to(coord: f32, max: f32) -> f32 {
-        if coord < 0.0 {
-            max - coord
-        } else if coord > max {
-            coord - max
-        } else {
-            coord
-        }
+    /// Tourne le vaisseau vers un cap par le plus court chemin, sans dépasser sa vitesse de
+    /// rotation.
+    ///
+    /// # Arguments
+    /// * `heading` - Cap visé, en radians.
+    /// * `dt` - Durée de la rotation, en secondes.
+    pub fn turn_towards(&mut self, heading: f32, dt: f32) {
+        let delta = (heading - self.angle + PI).rem_euclid(2.0 * PI) - PI;
+        let max_turn = Self::ROTATION_SPEED * dt;
+        self.angle += delta.clamp(-max_turn, max_turn);
     }
 }
 
@@ -137,21 +167,26 @@ impl StellarObject for Spaceship {
         self.position
     }
 
+    /// # Retourne
+    /// `Vec2` - La position du vaisseau avant le dernier pas, dans le repère de `position()`.
+    fn previous_position(&self) -> Vec2 {
+        self.previous_position
+    }
+
     /// Met à jour la position du vaisseau en fonction de sa vitesse et de la poussée.
-    fn update_position(&mut self) {
+    fn update_position(&mut self, bounds: Vec2, dt: f32) {
         self.speed += vec2(
             self.thrust * self.angle.cos(),
             self.thrust * self.angle.sin(),
-        );
+        ) * dt;
 
-        if self.speed.length() > 1.0 {
-            self.speed = self.speed.normalize();
-        }
+        self.speed = self.speed.clamp_length_max(Self::MAX_SPEED);
 
-        self.speed *= 0.999; // Réduction de la vitesse pour simuler la friction
+        self.speed *= Self::FRICTION.powf(dt); // Réduction de la vitesse pour simuler la friction
 
-        self.position += self.speed;
-        self.position = Self::bound_pos(self.position); // Gestion des bords de l'écran
+        // Gestion des bords de l'arène
+        (self.position, self.previous_position) =
+            arena::move_wrapped(self.position, self.speed * dt, bounds);
     }
 
     /// # Retourne
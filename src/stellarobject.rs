@@ -2,36 +2,62 @@
 //!
 //! Ce module définit le trait `StellarObject`, utilisé pour représenter les objets stellaires (vaisseau saptial, astéroides...).
 
-use macroquad::prelude::*;
+use crate::arena::wrapped_delta;
+use macroquad::math::Vec2;
 
 /// Le trait `StellarObject` représente un objet stellaire dans le jeu.
-
+///
 /// Ce trait fournit des méthodes communes pour gérer la position, les collisions et le rayon
 /// des objets stellaires.
 pub trait StellarObject {
     /// Retourne la position de l'objet.
-
+    ///
     /// # Retourne
     /// * `Vec2` - Les coordonnées actuelles de l'objet dans l'espace à 2 dimensions.
     fn position(&self) -> Vec2;
 
+    /// Retourne la position de l'objet avant le dernier pas de simulation.
+    ///
+    /// Cette position est exprimée dans le même repère que `position()` (avant le retour
+    /// de l'autre côté de l'arène), ce qui permet d'interpoler entre les deux sans saut.
+    fn previous_position(&self) -> Vec2;
+
+    /// Retourne la position interpolée de l'objet entre les deux derniers pas de simulation.
+    ///
+    /// # Arguments
+    /// * `alpha` - Fraction du pas écoulée depuis la dernière mise à jour, entre 0 et 1.
+    ///
+    /// # Retourne
+    /// * `Vec2` - La position à utiliser pour l'affichage.
+    fn interpolated_position(&self, alpha: f32) -> Vec2 {
+        self.previous_position().lerp(self.position(), alpha)
+    }
+
     /// Met à jour la position de l'objet en fonction de sa vitesse.
     ///
-    /// Cette méthode déplace l'objet stellaire en fonction de sa vitesse et de sa direction.
-    fn update_position(&mut self);
+    /// Cette méthode déplace l'objet stellaire en fonction de sa vitesse et de sa direction,
+    /// proportionnellement au temps écoulé.
+    ///
+    /// # Arguments
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    /// * `dt` - Le temps écoulé depuis la dernière mise à jour, en secondes.
+    fn update_position(&mut self, bounds: Vec2, dt: f32);
 
     /// Vérifie si cet objet entre en collision avec un autre.
     ///
     /// # Arguments
     /// * `other` - Une référence à un autre objet implémentant `StellarObject`.
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
     ///
     /// # Retourne
     /// * un booléen - `true` si les deux objets sont en collision, `false` sinon.
     ///
     /// La collision est détectée en calculant la distance entre les deux objets
-    /// et en la comparant à la somme de leurs rayons.
-    fn check_collision(&self, other: &dyn StellarObject) -> bool {
-        let distance = self.position().distance(other.position());
+    /// et en la comparant à la somme de leurs rayons. L'arène étant torique, la distance tient
+    /// compte du passage d'un bord à l'autre : un objet à cheval sur le bord droit peut toucher
+    /// un objet situé contre le bord gauche.
+    fn check_collision(&self, other: &dyn StellarObject, bounds: Vec2) -> bool {
+        let distance = wrapped_delta(self.position(), other.position(), bounds).length();
         let combined_radius = self.radius() + other.radius();
         distance < combined_radius
     }
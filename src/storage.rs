@@ -0,0 +1,30 @@
+//! Module `storage.rs`
+//!
+//! Ce module définit l'emplacement des fichiers conservés d'une partie à l'autre (meilleurs scores,
+//! commandes...) dans le dossier de données de l'utilisateur.
+
+use std::path::PathBuf;
+
+/// Nom du dossier du jeu dans le dossier de données de l'utilisateur.
+const GAME_DIR: &str = "asteroid";
+
+/// Retourne le dossier de données du jeu, ou `None` si le dossier de l'utilisateur est
+/// introuvable.
+///
+/// Le dossier de l'utilisateur est `%APPDATA%` sous Windows, `~/Library/Application Support` sous
+/// macOS et `$XDG_DATA_HOME` (à défaut `~/.local/share`) ailleurs.
+pub fn data_dir() -> Option<PathBuf> {
+    let env = |name: &str| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    let data_dir = if cfg!(target_os = "windows") {
+        env("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        env("HOME").map(|home| home.join("Library/Application Support"))
+    } else {
+        env("XDG_DATA_HOME").or_else(|| env("HOME").map(|home| home.join(".local/share")))
+    };
+    data_dir.map(|dir| dir.join(GAME_DIR))
+}
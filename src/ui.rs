@@ -0,0 +1,102 @@
+//! Module `ui.rs`
+//!
+//! Ce module définit les éléments d'interface communs aux différents écrans du jeu (boutons, textes centrés).
+
+use macroquad::prelude::*;
+
+/// Largeur d'un bouton de menu.
+const BUTTON_WIDTH: f32 = 400.0;
+
+/// Hauteur d'un bouton de menu.
+const BUTTON_HEIGHT: f32 = 75.0;
+
+/// Espace vertical entre le haut de deux boutons consécutifs.
+const BUTTON_SPACING: f32 = 90.0;
+
+/// Taille du texte des boutons.
+const BUTTON_FONT_SIZE: f32 = 50.0;
+
+/// Structure `Button` qui représente un bouton rectangulaire cliquable.
+pub struct Button {
+    label: String, // Texte affiché sur le bouton
+    rect: Rect,    // Position et dimensions du bouton
+    color: Color,  // Couleur de fond du bouton
+}
+
+impl Button {
+    /// Crée un nouveau bouton.
+    ///
+    /// # Arguments
+    /// * `label` - Texte affiché sur le bouton.
+    /// * `rect` - Position et dimensions du bouton.
+    /// * `color` - Couleur de fond du bouton.
+    pub fn new(label: impl Into<String>, rect: Rect, color: Color) -> Self {
+        Self {
+            label: label.into(),
+            rect,
+            color,
+        }
+    }
+
+    /// Dessine le bouton, avec son texte centré.
+    pub fn draw(&self) {
+        draw_rectangle(
+            self.rect.x,
+            self.rect.y,
+            self.rect.w,
+            self.rect.h,
+            self.color,
+        );
+        let size = measure_text(&self.label, None, BUTTON_FONT_SIZE as u16, 1.0);
+        draw_text(
+            &self.label,
+            self.rect.x + (self.rect.w - size.width) / 2.0,
+            self.rect.y + (self.rect.h + size.offset_y) / 2.0,
+            BUTTON_FONT_SIZE,
+            BLACK,
+        );
+    }
+
+    /// Indique si le bouton vient d'être cliqué avec le bouton gauche de la souris.
+    pub fn is_clicked(&self) -> bool {
+        is_mouse_button_pressed(MouseButton::Left) && self.rect.contains(mouse_position().into())
+    }
+}
+
+/// Crée une colonne de boutons centrée horizontalement.
+///
+/// # Arguments
+/// * `top` - Ordonnée du haut du premier bouton.
+/// * `items` - Texte et couleur de chaque bouton, de haut en bas.
+///
+/// # Retourne
+/// * `Vec<Button>` - Les boutons, dans le même ordre que `items`.
+pub fn menu(top: f32, items: &[(&str, Color)]) -> Vec<Button> {
+    let x = screen_width() / 2.0 - BUTTON_WIDTH / 2.0;
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, (label, color))| {
+            let y = top + index as f32 * BUTTON_SPACING;
+            Button::new(*label, Rect::new(x, y, BUTTON_WIDTH, BUTTON_HEIGHT), *color)
+        })
+        .collect()
+}
+
+/// Dessine un texte centré horizontalement à l'écran.
+///
+/// # Arguments
+/// * `text` - Texte à afficher.
+/// * `y` - Ordonnée de la ligne de base du texte.
+/// * `font_size` - Taille du texte.
+/// * `color` - Couleur du texte.
+pub fn draw_centered_text(text: &str, y: f32, font_size: f32, color: Color) {
+    let size = measure_text(text, None, font_size as u16, 1.0);
+    draw_text(
+        text,
+        screen_width() / 2.0 - size.width / 2.0,
+        y,
+        font_size,
+        color,
+    );
+}
@@ -0,0 +1,367 @@
+//! Module `wave.rs`
+//!
+//! Ce module définit la progression des vagues d'astéroïdes : nombre d'astéroïdes, plage de vitesses
+//! et répartition des tailles de chaque vague, qui augmentent avec le niveau, ainsi que l'arrivée
+//! des puits de gravité et des soucoupes.
+
+use std::ops::RangeInclusive;
+
+/// Caractéristiques d'une vague d'astéroïdes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wave {
+    pub level: u32,               // Numéro de la vague, à partir de 1
+    pub count: usize,             // Nombre d'astéroïdes générés
+    pub speed_range: (f32, f32),  // Vitesses minimale et maximale, en pixels par seconde
+    pub size_weights: [f32; 3],   // Poids des tailles petite, moyenne et grande
+    pub wells: usize,             // Nombre de puits de gravité placés au début de la vague
+    pub max_wells: usize,         // Nombre maximal de puits présents en même temps
+    pub well_spawn_rate: f32, // Nombre moyen de puits apparaissant par seconde en cours de vague
+    pub saucer_interval: f32, // Délai entre deux soucoupes, en secondes (0 pour aucune)
+    pub saucer_accuracy: f32, // Précision des tirs des soucoupes, entre 0 et 1
+    pub small_saucer_chance: f32, // Probabilité qu'une soucoupe soit petite
+}
+
+/// Structure `WaveConfig` qui décrit l'évolution des vagues avec le niveau.
+///
+/// Le nombre, la vitesse et la répartition des tailles des astéroïdes partent d'une valeur initiale
+/// (vague 1) et augmentent linéairement à chaque vague, jusqu'à un plafond pour le nombre et la
+/// vitesse. Les puits de gravité et les soucoupes n'apparaissent qu'à partir d'une vague donnée,
+/// après laquelle la précision des soucoupes et la part des petites progressent de la même façon.
+/// Les réglages identiques pour toutes les vagues sont regroupés dans `GameplayConfig`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaveConfig {
+    pub initial_count: usize,      // Nombre d'astéroïdes de la première vague
+    pub count_increment: usize,    // Astéroïdes ajoutés à chaque vague
+    pub max_count: usize,          // Nombre maximal d'astéroïdes d'une vague
+    pub initial_speed: (f32, f32), // Plage de vitesses de la première vague
+    pub speed_increment: f32,      // Vitesse ajoutée aux deux bornes à chaque vague
+    pub max_speed: f32,            // Vitesse maximale d'un astéroïde
+    pub initial_size_weights: [f32; 3], // Poids des tailles (petite, moyenne, grande) de la première vague
+    pub size_weights_increment: [f32; 3], // Poids ajoutés à chaque vague
+    pub wells_start_level: u32, // Première vague comportant des puits de gravité (0 pour n'en jamais placer)
+    pub max_wells: usize,       // Nombre maximal de puits présents en même temps
+    pub well_spawn_rate: f32,   // Nombre moyen de puits apparaissant par seconde en cours de vague
+    pub saucer_start_level: u32, // Première vague traversée par des soucoupes (0 pour n'en jamais envoyer)
+    pub saucer_interval: f32,    // Délai entre deux soucoupes, en secondes
+    pub initial_saucer_accuracy: f32, // Précision des soucoupes de la première vague qui en comporte
+    pub saucer_accuracy_increment: f32, // Précision ajoutée à chaque vague
+    pub max_saucer_accuracy: f32,     // Précision maximale des soucoupes (1 pour un tir parfait)
+    pub initial_small_saucer_chance: f32, // Probabilité qu'une soucoupe soit petite, à la première vague qui en comporte
+    pub small_saucer_chance_increment: f32, // Probabilité ajoutée à chaque vague
+    pub max_small_saucer_chance: f32,     // Probabilité maximale qu'une soucoupe soit petite
+}
+
+impl Default for WaveConfig {
+    /// Progression par défaut : la première vague correspond au jeu d'origine (10 astéroïdes,
+    /// tailles équiprobables), puis les vagues suivantes sont plus nombreuses, plus rapides et
+    /// comptent davantage de gros astéroïdes. Les puits de gravité apparaissent à partir de la
+    /// troisième vague, les soucoupes à partir de la deuxième.
+    fn default() -> Self {
+        Self {
+            initial_count: 10,
+            count_increment: 2,
+            max_count: 40,
+            initial_speed: (50.0, 70.0),
+            speed_increment: 10.0,
+            max_speed: 200.0,
+            initial_size_weights: [1.0, 1.0, 1.0],
+            size_weights_increment: [0.0, 0.25, 0.5],
+            wells_start_level: 3,
+            max_wells: 2,
+            well_spawn_rate: 0.02,
+            saucer_start_level: 2,
+            saucer_interval: 20.0,
+            initial_saucer_accuracy: 0.3,
+            saucer_accuracy_increment: 0.1,
+            max_saucer_accuracy: 0.9,
+            initial_small_saucer_chance: 0.3,
+            small_saucer_chance_increment: 0.1,
+            max_small_saucer_chance: 0.9,
+        }
+    }
+}
+
+impl WaveConfig {
+    /// Retourne chaque paramètre de la progression avec son nom, pour l'enregistrement dans les
+    /// replays.
+    pub fn fields(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("initial_count", self.initial_count as f64),
+            ("count_increment", self.count_increment as f64),
+            ("max_count", self.max_count as f64),
+            ("initial_speed_min", self.initial_speed.0 as f64),
+            ("initial_speed_max", self.initial_speed.1 as f64),
+            ("speed_increment", self.speed_increment as f64),
+            ("max_speed", self.max_speed as f64),
+            ("initial_small_weight", self.initial_size_weights[0] as f64),
+            ("initial_medium_weight", self.initial_size_weights[1] as f64),
+            ("initial_large_weight", self.initial_size_weights[2] as f64),
+            (
+                "small_weight_increment",
+                self.size_weights_increment[0] as f64,
+            ),
+            (
+                "medium_weight_increment",
+                self.size_weights_increment[1] as f64,
+            ),
+            (
+                "large_weight_increment",
+                self.size_weights_increment[2] as f64,
+            ),
+            ("wells_start_level", self.wells_start_level as f64),
+            ("max_wells", self.max_wells as f64),
+            ("well_spawn_rate", self.well_spawn_rate as f64),
+            ("saucer_start_level", self.saucer_start_level as f64),
+            ("saucer_interval", self.saucer_interval as f64),
+            (
+                "initial_saucer_accuracy",
+                self.initial_saucer_accuracy as f64,
+            ),
+            (
+                "saucer_accuracy_increment",
+                self.saucer_accuracy_increment as f64,
+            ),
+            ("max_saucer_accuracy", self.max_saucer_accuracy as f64),
+            (
+                "initial_small_saucer_chance",
+                self.initial_small_saucer_chance as f64,
+            ),
+            (
+                "small_saucer_chance_increment",
+                self.small_saucer_chance_increment as f64,
+            ),
+            (
+                "max_small_saucer_chance",
+                self.max_small_saucer_chance as f64,
+            ),
+        ]
+    }
+
+    /// Retourne la plage des valeurs acceptées pour un paramètre, ou `None` si le nom est inconnu.
+    pub fn field_range(name: &str) -> Option<RangeInclusive<f64>> {
+        let range = match name {
+            "initial_count" | "count_increment" | "max_count" => 0.0..=1000.0,
+            "initial_speed_min" | "initial_speed_max" | "speed_increment" | "max_speed" => {
+                0.0..=10_000.0
+            }
+            "initial_small_weight" | "initial_medium_weight" | "initial_large_weight" => {
+                0.0..=1000.0
+            }
+            "small_weight_increment" | "medium_weight_increment" | "large_weight_increment" => {
+                -1000.0..=1000.0
+            }
+            "wells_start_level" | "saucer_start_level" => 0.0..=1e6,
+            "max_wells" => 0.0..=100.0,
+            "well_spawn_rate" => 0.0..=10.0,
+            "saucer_interval" => 0.0..=3600.0,
+            "initial_saucer_accuracy"
+            | "max_saucer_accuracy"
+            | "initial_small_saucer_chance"
+            | "max_small_saucer_chance" => 0.0..=1.0,
+            "saucer_accuracy_increment" | "small_saucer_chance_increment" => -1.0..=1.0,
+            _ => return None,
+        };
+        Some(range)
+    }
+
+    /// Modifie un paramètre de la progression à partir de son nom.
+    ///
+    /// # Arguments
+    /// * `name` - Le nom du paramètre, tel que donné par `fields`.
+    /// * `value` - Sa nouvelle valeur.
+    ///
+    /// # Retourne
+    /// * `bool` - `false` si le nom est inconnu ou la valeur hors de sa plage (`field_range`),
+    ///   auquel cas rien n'est modifié.
+    pub fn set_field(&mut self, name: &str, value: f64) -> bool {
+        if !Self::field_range(name).is_some_and(|range| range.contains(&value)) {
+            return false;
+        }
+        match name {
+            "initial_count" => self.initial_count = value as usize,
+            "count_increment" => self.count_increment = value as usize,
+            "max_count" => self.max_count = value as usize,
+            "initial_speed_min" => self.initial_speed.0 = value as f32,
+            "initial_speed_max" => self.initial_speed.1 = value as f32,
+            "speed_increment" => self.speed_increment = value as f32,
+            "max_speed" => self.max_speed = value as f32,
+            "initial_small_weight" => self.initial_size_weights[0] = value as f32,
+            "initial_medium_weight" => self.initial_size_weights[1] = value as f32,
+            "initial_large_weight" => self.initial_size_weights[2] = value as f32,
+            "small_weight_increment" => self.size_weights_increment[0] = value as f32,
+            "medium_weight_increment" => self.size_weights_increment[1] = value as f32,
+            "large_weight_increment" => self.size_weights_increment[2] = value as f32,
+            "wells_start_level" => self.wells_start_level = value as u32,
+            "max_wells" => self.max_wells = value as usize,
+            "well_spawn_rate" => self.well_spawn_rate = value as f32,
+            "saucer_start_level" => self.saucer_start_level = value as u32,
+            "saucer_interval" => self.saucer_interval = value as f32,
+            "initial_saucer_accuracy" => self.initial_saucer_accuracy = value as f32,
+            "saucer_accuracy_increment" => self.saucer_accuracy_increment = value as f32,
+            "max_saucer_accuracy" => self.max_saucer_accuracy = value as f32,
+            "initial_small_saucer_chance" => self.initial_small_saucer_chance = value as f32,
+            "small_saucer_chance_increment" => self.small_saucer_chance_increment = value as f32,
+            "max_small_saucer_chance" => self.max_small_saucer_chance = value as f32,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Calcule les caractéristiques d'une vague.
+    ///
+    /// # Arguments
+    /// * `level` - Numéro de la vague, à partir de 1.
+    ///
+    /// # Retourne
+    /// * `Wave` - Les caractéristiques de la vague.
+    pub fn wave(&self, level: u32) -> Wave {
+        let steps = level.saturating_sub(1);
+        let count = self
+            .initial_count
+            .saturating_add(self.count_increment.saturating_mul(steps as usize))
+            .min(self.max_count);
+
+        let speed_bonus = self.speed_increment * steps as f32;
+        let min_speed = (self.initial_speed.0 + speed_bonus).min(self.max_speed);
+        let max_speed = (self.initial_speed.1 + speed_bonus).min(self.max_speed);
+
+        let mut size_weights = self.initial_size_weights;
+        for (weight, increment) in size_weights.iter_mut().zip(self.size_weights_increment) {
+            *weight = (*weight + increment * steps as f32).max(0.0);
+        }
+
+        // Un puits de plus toutes les deux vagues à partir de `wells_start_level`.
+        let max_wells = if self.wells_start_level > 0 && level >= self.wells_start_level {
+            self.max_wells
+        } else {
+            0
+        };
+        let wells =
+            (((level.saturating_sub(self.wells_start_level)) / 2 + 1) as usize).min(max_wells);
+
+        // Les soucoupes visent de mieux en mieux, et les petites sont de plus en plus fréquentes,
+        // à partir de `saucer_start_level`.
+        let saucers = self.saucer_start_level > 0 && level >= self.saucer_start_level;
+        let saucer_steps = level.saturating_sub(self.saucer_start_level) as f32;
+        let saucer_accuracy = (self.initial_saucer_accuracy
+            + self.saucer_accuracy_increment * saucer_steps)
+            .min(self.max_saucer_accuracy)
+            .clamp(0.0, 1.0);
+        let small_saucer_chance = (self.initial_small_saucer_chance
+            + self.small_saucer_chance_increment * saucer_steps)
+            .min(self.max_small_saucer_chance)
+            .clamp(0.0, 1.0);
+
+        Wave {
+            level,
+            count,
+            speed_range: (min_speed, max_speed.max(min_speed)),
+            size_weights,
+            wells,
+            max_wells,
+            well_spawn_rate: if max_wells > 0 {
+                self.well_spawn_rate.max(0.0)
+            } else {
+                0.0
+            },
+            saucer_interval: if saucers {
+                self.saucer_interval.max(0.0)
+            } else {
+                0.0
+            },
+            saucer_accuracy,
+            small_saucer_chance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waves_get_harder() {
+        let config = WaveConfig::default();
+        let first = config.wave(1);
+        let second = config.wave(2);
+
+        assert_eq!(first.count, config.initial_count);
+        assert!(second.count > first.count);
+        assert!(second.speed_range.0 > first.speed_range.0);
+        assert!(second.size_weights[2] > first.size_weights[2]);
+    }
+
+    #[test]
+    fn test_wells_appear_from_start_level() {
+        let config = WaveConfig::default();
+
+        assert_eq!(config.wave(config.wells_start_level - 1).wells, 0);
+        assert_eq!(
+            config.wave(config.wells_start_level - 1).well_spawn_rate,
+            0.0
+        );
+        assert_eq!(config.wave(config.wells_start_level).wells, 1);
+        assert_eq!(config.wave(1000).wells, config.max_wells);
+
+        let never = WaveConfig {
+            wells_start_level: 0,
+            ..config
+        };
+        assert_eq!(never.wave(1000).max_wells, 0);
+    }
+
+    #[test]
+    fn test_saucers_aim_better_with_level() {
+        let config = WaveConfig::default();
+
+        assert_eq!(
+            config.wave(config.saucer_start_level - 1).saucer_interval,
+            0.0
+        );
+        let first = config.wave(config.saucer_start_level);
+        assert_eq!(first.saucer_interval, config.saucer_interval);
+        assert_eq!(first.saucer_accuracy, config.initial_saucer_accuracy);
+        assert!(config.wave(config.saucer_start_level + 1).saucer_accuracy > first.saucer_accuracy);
+        assert_eq!(
+            config.wave(1000).saucer_accuracy,
+            config.max_saucer_accuracy
+        );
+        assert_eq!(
+            first.small_saucer_chance,
+            config.initial_small_saucer_chance
+        );
+        assert_eq!(
+            config.wave(1000).small_saucer_chance,
+            config.max_small_saucer_chance
+        );
+    }
+
+    #[test]
+    fn test_every_field_can_be_set_by_name() {
+        let config = WaveConfig::default();
+        for (name, value) in config.fields() {
+            let range = WaveConfig::field_range(name).unwrap();
+            let other = if value == *range.start() {
+                *range.end()
+            } else {
+                *range.start()
+            };
+            let mut changed = config;
+            assert!(changed.set_field(name, other), "{}", name);
+            assert!(changed.fields().contains(&(name, other)), "{}", name);
+        }
+        assert!(!WaveConfig::default().set_field("inconnu", 0.0));
+        assert!(!WaveConfig::default().set_field("max_count", 1e12));
+        assert!(!WaveConfig::default().set_field("max_saucer_accuracy", f64::NAN));
+    }
+
+    #[test]
+    fn test_waves_are_capped() {
+        let config = WaveConfig::default();
+        let wave = config.wave(1000);
+
+        assert_eq!(wave.count, config.max_count);
+        assert!(wave.speed_range.0 <= wave.speed_range.1);
+        assert!(wave.speed_range.1 <= config.max_speed);
+    }
+}
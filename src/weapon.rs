@@ -0,0 +1,273 @@
+//! Module `weapon.rs`
+//!
+//! Ce module définit les armes du vaisseau (`Weapon`) et le rayon laser (`Beam`) tiré par l'une
+//! d'elles. Chaque arme a son propre délai entre deux tirs et son propre type de projectile.
+
+use crate::arena;
+use crate::missile::Missile;
+use crate::stellarobject::StellarObject;
+use macroquad::math::{vec2, Vec2};
+
+/// Armes disponibles pour le vaisseau.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Weapon {
+    #[default]
+    Cannon, // Un missile à la fois, l'arme d'origine
+    Spread,    // Trois missiles en éventail
+    RapidFire, // Petits missiles rapides, tirés en rafale
+    Laser,     // Rayon instantané qui traverse tous les astéroïdes sur sa trajectoire
+    Homing,    // Missiles plus lents qui se dirigent vers l'astéroïde le plus proche
+}
+
+impl Weapon {
+    /// Toutes les armes, dans l'ordre où le joueur les fait défiler.
+    pub const ALL: [Weapon; 5] = [
+        Weapon::Cannon,
+        Weapon::Spread,
+        Weapon::RapidFire,
+        Weapon::Laser,
+        Weapon::Homing,
+    ];
+
+    // Écart entre deux missiles de l'éventail, en radians
+    const SPREAD_ANGLE: f32 = 0.2;
+
+    /// Retourne le nom de l'arme, affiché à l'écran.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weapon::Cannon => "Canon",
+            Weapon::Spread => "Dispersion",
+            Weapon::RapidFire => "Tir rapide",
+            Weapon::Laser => "Laser",
+            Weapon::Homing => "Missiles guidés",
+        }
+    }
+
+    /// Retourne l'arme suivante dans `ALL`, en revenant à la première après la dernière.
+    pub fn next(&self) -> Weapon {
+        let index = Self::ALL
+            .iter()
+            .position(|weapon| weapon == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Retourne l'arme suivante dans `ALL`, ou la même s'il s'agit déjà de la dernière.
+    pub fn upgrade(&self) -> Weapon {
+        match Self::ALL.iter().position(|weapon| weapon == self) {
+            Some(index) if index + 1 < Self::ALL.len() => Self::ALL[index + 1],
+            _ => *self,
+        }
+    }
+
+    /// Calcule le délai entre deux tirs de cette arme.
+    ///
+    /// # Arguments
+    /// * `base` - Délai entre deux tirs du canon, en secondes.
+    pub fn cooldown(&self, base: f32) -> f32 {
+        match self {
+            Weapon::Cannon => base,
+            Weapon::Spread => base * 2.5,
+            Weapon::RapidFire => base * 0.4,
+            Weapon::Laser => base * 4.0,
+            Weapon::Homing => base * 3.0,
+        }
+    }
+
+    /// Indique si l'arme tire en continu tant que la gâchette est maintenue, au rythme de son délai
+    /// entre deux tirs. Les autres armes tirent une fois par appui.
+    pub fn is_automatic(&self) -> bool {
+        *self == Weapon::RapidFire
+    }
+
+    /// Calcule le nombre maximal de missiles en vol avec cette arme.
+    ///
+    /// # Arguments
+    /// * `base` - Nombre maximal de missiles en vol avec le canon.
+    pub fn max_missiles(&self, base: usize) -> usize {
+        match self {
+            Weapon::Spread | Weapon::RapidFire => base * 3,
+            Weapon::Cannon | Weapon::Laser | Weapon::Homing => base,
+        }
+    }
+
+    /// Crée les missiles d'un tir. Le laser ne tire aucun missile : voir `Beam`.
+    ///
+    /// # Arguments
+    /// * `position` - Point de départ des missiles, à l'avant du vaisseau.
+    /// * `angle` - Orientation du vaisseau, en radians.
+    /// * `launcher_velocity` - Vitesse du vaisseau, transmise aux missiles.
+    /// * `lifetime` - Durée de vie des missiles, en secondes.
+    ///
+    /// # Retourne
+    /// * `Vec<Missile>` - Les missiles tirés.
+    pub fn missiles(
+        &self,
+        position: Vec2,
+        angle: f32,
+        launcher_velocity: Vec2,
+        lifetime: f32,
+    ) -> Vec<Missile> {
+        let missile = |angle: f32, speed: f32, radius: f32, homing: bool| {
+            Missile::new(
+                position,
+                Vec2::from_angle(angle) * speed + launcher_velocity,
+                radius,
+                lifetime,
+                homing,
+            )
+        };
+        match self {
+            Weapon::Cannon => vec![missile(angle, Missile::MISSILE_VITESSE, 3.0, false)],
+            Weapon::Spread => [-Self::SPREAD_ANGLE, 0.0, Self::SPREAD_ANGLE]
+                .into_iter()
+                .map(|offset| missile(angle + offset, Missile::MISSILE_VITESSE, 3.0, false))
+                .collect(),
+            Weapon::RapidFire => vec![missile(angle, Missile::MISSILE_VITESSE * 1.2, 2.0, false)],
+            Weapon::Laser => vec![],
+            Weapon::Homing => vec![missile(angle, Missile::MISSILE_VITESSE * 0.7, 4.0, true)],
+        }
+    }
+}
+
+/// Structure `Beam` qui représente un tir de laser.
+///
+/// Le rayon est instantané : il touche au moment du tir tous les astéroïdes qui croisent le
+/// segment, puis reste affiché un court instant.
+pub struct Beam {
+    origin: Vec2,    // Point de départ du rayon
+    direction: Vec2, // Direction du rayon (vecteur unitaire)
+    length: f32,     // Longueur du rayon, en pixels
+    time_left: f32,  // Durée d'affichage restante, en secondes
+}
+
+impl Beam {
+    // Portée du laser, en pixels
+    pub const RANGE: f32 = 450.0;
+    // Durée d'affichage du rayon, en secondes
+    pub const DURATION: f32 = 0.15;
+
+    /// Crée un rayon laser.
+    ///
+    /// # Arguments
+    /// * `origin` - Point de départ du rayon, à l'avant du vaisseau.
+    /// * `angle` - Orientation du rayon, en radians.
+    pub fn new(origin: Vec2, angle: f32) -> Self {
+        Self {
+            origin,
+            direction: Vec2::from_angle(angle),
+            length: Self::RANGE,
+            time_left: Self::DURATION,
+        }
+    }
+
+    /// Retourne le point de départ du rayon.
+    pub fn origin(&self) -> Vec2 {
+        self.origin
+    }
+
+    /// Retourne la direction du rayon (vecteur unitaire).
+    pub fn direction(&self) -> Vec2 {
+        self.direction
+    }
+
+    /// Retourne l'extrémité du rayon, éventuellement hors de l'arène.
+    pub fn end(&self) -> Vec2 {
+        self.origin + self.direction * self.length
+    }
+
+    /// Retourne la fraction de la durée d'affichage restante, entre 0 et 1.
+    pub fn fade(&self) -> f32 {
+        (self.time_left / Self::DURATION).clamp(0.0, 1.0)
+    }
+
+    /// Fait avancer la durée d'affichage du rayon.
+    ///
+    /// # Retourne
+    /// * un booléen - `true` tant que le rayon doit rester affiché.
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.time_left -= dt;
+        self.time_left > 0.0
+    }
+
+    /// Lance le rayon contre un objet.
+    ///
+    /// Le rayon peut être plus long que la moitié de l'arène : toutes les copies de l'objet que le
+    /// segment peut croiser sont testées, et la plus proche de l'origine est retenue.
+    ///
+    /// # Arguments
+    /// * `object` - L'objet visé.
+    /// * `bounds` - Les dimensions de l'arène (largeur, hauteur).
+    ///
+    /// # Retourne
+    /// * `Option<Vec2>` - Le point du rayon le plus proche du centre de l'objet s'il est touché.
+    pub fn hit(&self, object: &dyn StellarObject, bounds: Vec2) -> Option<Vec2> {
+        let to_object = arena::wrapped_delta(self.origin, object.position(), bounds);
+        let reach_x = (self.length / bounds.x).ceil() as i32;
+        let reach_y = (self.length / bounds.y).ceil() as i32;
+        (-reach_x..=reach_x)
+            .flat_map(|i| (-reach_y..=reach_y).map(move |j| vec2(i as f32, j as f32) * bounds))
+            .filter_map(|offset| {
+                let copy = to_object + offset;
+                let along = copy.dot(self.direction).clamp(0.0, self.length);
+                ((self.direction * along).distance(copy) < object.radius()).then_some(along)
+            })
+            .min_by(f32::total_cmp)
+            .map(|along| arena::wrap_position(self.origin + self.direction * along, bounds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroid::Asteroid;
+    use macroquad::math::vec2;
+
+    #[test]
+    fn test_weapons_cycle() {
+        let mut weapon = Weapon::default();
+        for _ in 0..Weapon::ALL.len() {
+            weapon = weapon.next();
+        }
+        assert_eq!(weapon, Weapon::default());
+        assert_eq!(Weapon::Cannon.next(), Weapon::Spread);
+        assert_eq!(Weapon::Cannon.upgrade(), Weapon::Spread);
+        assert_eq!(Weapon::Homing.upgrade(), Weapon::Homing);
+    }
+
+    #[test]
+    fn test_laser_pierces_everything_in_range() {
+        let bounds = vec2(1000.0, 1000.0);
+        let beam = Beam::new(vec2(100.0, 500.0), 0.0);
+        let in_line = Asteroid::new_at(vec2(300.0, 510.0), 15.0, Vec2::ZERO);
+        let behind = Asteroid::new_at(vec2(400.0, 480.0), 30.0, Vec2::ZERO);
+        let beside = Asteroid::new_at(vec2(300.0, 560.0), 15.0, Vec2::ZERO);
+        let too_far = Asteroid::new_at(vec2(100.0 + Beam::RANGE + 40.0, 500.0), 30.0, Vec2::ZERO);
+        let at_back = Asteroid::new_at(vec2(60.0, 500.0), 15.0, Vec2::ZERO);
+
+        assert_eq!(beam.hit(&in_line, bounds), Some(vec2(300.0, 500.0)));
+        assert!(beam.hit(&behind, bounds).is_some());
+        assert!(beam.hit(&beside, bounds).is_none());
+        assert!(beam.hit(&too_far, bounds).is_none());
+        assert!(beam.hit(&at_back, bounds).is_none());
+    }
+
+    #[test]
+    fn test_laser_crosses_edges() {
+        let bounds = vec2(1000.0, 1000.0);
+        let beam = Beam::new(vec2(950.0, 500.0), 0.0);
+        let across = Asteroid::new_at(vec2(50.0, 500.0), 15.0, Vec2::ZERO);
+
+        assert_eq!(beam.hit(&across, bounds), Some(vec2(50.0, 500.0)));
+    }
+
+    #[test]
+    fn test_laser_reaches_past_half_of_a_non_square_arena() {
+        let bounds = vec2(1280.0, 720.0);
+        let beam = Beam::new(vec2(640.0, 100.0), std::f32::consts::FRAC_PI_2);
+        let ahead = Asteroid::new_at(vec2(640.0, 500.0), 15.0, Vec2::ZERO);
+
+        let hit = beam.hit(&ahead, bounds).unwrap();
+        assert!(hit.distance(vec2(640.0, 500.0)) < 1e-3);
+    }
+}
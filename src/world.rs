@@ -0,0 +1,1742 @@
+//! Module `world.rs`
+//!
+//! Ce module définit la structure `World`, qui regroupe tous les objets du jeu (astéroïdes, vaisseau,
+//! missiles) ainsi que les dimensions de l'arène. La simulation avance sans aucun contexte graphique,
+//! ce qui permet de la faire tourner sans fenêtre (tests, expériences d'équilibrage...).
+
+use crate::arena::{self, wrapped_delta};
+use crate::asteroid::{Asteroid, Impact};
+use crate::gameplay::GameplayConfig;
+use crate::gravitywell::GravityWell;
+use crate::grid::UniformGrid;
+use crate::missile::Missile;
+use crate::powerup::{PowerUp, PowerUpKind};
+use crate::saucer::{Saucer, SaucerSize};
+use crate::spaceship::Spaceship;
+use crate::stellarobject::StellarObject;
+use crate::wave::{Wave, WaveConfig};
+use crate::weapon::{Beam, Weapon};
+use ::rand::rngs::SmallRng;
+use ::rand::{Rng, SeedableRng};
+use macroquad::math::{vec2, Vec2};
+use std::f32::consts::TAU;
+
+/// Durée d'un pas de simulation, en secondes.
+pub const TIME_STEP: f32 = 1.0 / 60.0;
+
+/// Durée de l'invincibilité du vaisseau après un choc encaissé par son bouclier, en secondes.
+const HIT_INVINCIBILITY: f32 = 0.5;
+
+/// Vitesse relative de référence des collisions : à cette vitesse, les dégâts valent le double
+/// de ceux d'un choc à l'arrêt, en pixels par seconde.
+const IMPACT_REFERENCE_SPEED: f32 = 100.0;
+
+/// Durée de l'explosion du vaisseau détruit, avant qu'il puisse réapparaître, en secondes.
+pub const EXPLOSION_DURATION: f32 = 1.0;
+
+/// Poussée appliquée au vaisseau, en pixels par seconde au carré.
+const THRUST: f32 = 18.0;
+
+/// Rétro-poussée appliquée au vaisseau, en pixels par seconde au carré.
+const REVERSE_THRUST: f32 = -9.0;
+
+/// Durée des effets temporaires des bonus (invincibilité, multiplicateur, ralenti), en secondes.
+pub const POWERUP_DURATION: f32 = 8.0;
+
+/// Multiplicateur des points gagnés pendant l'effet du bonus correspondant.
+const SCORE_MULTIPLIER: i32 = 2;
+
+/// Nombre de coups au but consécutifs qui font progresser le multiplicateur de combo d'un cran.
+const COMBO_STEP: u32 = 5;
+
+/// Multiplicateur de combo maximal.
+const MAX_COMBO_MULTIPLIER: i32 = 4;
+
+/// Bonus de fin de vague pour une précision parfaite, réduit en proportion de la précision.
+const ACCURACY_BONUS: f32 = 500.0;
+
+/// Bonus de fin de vague, multiplié par le numéro de la vague terminée.
+const WAVE_CLEAR_BONUS: i32 = 100;
+
+/// Points perdus lorsque le vaisseau percute un astéroïde sans être invincible.
+const COLLISION_PENALTY: i32 = 10;
+
+/// Facteur de vitesse des astéroïdes pendant l'effet du bonus de ralenti.
+const SLOW_TIME_FACTOR: f32 = 0.5;
+
+/// Durée d'affichage de l'onde de choc du bouclier, en secondes.
+pub const BASH_DURATION: f32 = 0.3;
+
+/// Commandes du joueur pour un pas de simulation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerInput {
+    pub turn_left: bool,  // Rotation dans le sens inverse des aiguilles d'une montre
+    pub turn_right: bool, // Rotation dans le sens des aiguilles d'une montre
+    pub thrust: bool,     // Poussée vers l'avant
+    pub reverse: bool,    // Rétro-poussée
+    pub fire: bool,       // Tir de l'arme sélectionnée
+    pub fire_held: bool, // Gâchette maintenue : les armes automatiques tirent dès que leur délai est écoulé
+    pub switch_weapon: bool, // Passage à l'arme suivante
+    pub hyperspace: bool, // Saut en hyperespace
+    pub shield_bash: bool, // Onde de choc du bouclier
+    pub aim: Option<u16>, // Cap visé au stick ou à la souris, en 65536es de tour
+    pub throttle: u8,    // Poussée analogique, en 255es de la poussée maximale
+}
+
+impl PlayerInput {
+    /// Vise un cap : le vaisseau tourne vers lui tant qu'aucune touche de rotation n'est enfoncée.
+    /// Le cap est arrondi au 65536e de tour pour être enregistré à l'identique dans les replays.
+    ///
+    /// # Arguments
+    /// * `heading` - Cap visé, en radians.
+    pub fn set_aim(&mut self, heading: f32) {
+        let turns = heading.rem_euclid(TAU) / TAU;
+        self.aim = Some((turns * 65536.0).round() as u32 as u16);
+    }
+
+    /// Retourne le cap visé, en radians.
+    pub fn aim_heading(&self) -> Option<f32> {
+        self.aim.map(|aim| aim as f32 / 65536.0 * TAU)
+    }
+
+    /// Règle la poussée analogique, arrondie au 255e pour les replays.
+    ///
+    /// # Arguments
+    /// * `amount` - Part de la poussée maximale, entre 0 et 1.
+    pub fn set_throttle(&mut self, amount: f32) {
+        self.throttle = (amount.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8;
+    }
+}
+
+/// État de la partie après un pas de simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorldStatus {
+    Running,     // La partie continue
+    WaveCleared, // Tous les astéroïdes ont été détruits, la vague suivante a commencé
+    Defeat,      // Le vaisseau a été détruit
+}
+
+/// Bilan d'une vague : points gagnés en détruisant astéroïdes et soucoupes, tirs et bonus de fin
+/// de vague.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WaveSummary {
+    pub level: u32,              // Numéro de la vague
+    pub destruction_points: i32, // Points gagnés par les destructions, multiplicateurs compris
+    pub best_combo: u32,         // Plus longue série de coups au but sans tir manqué
+    pub shots: u32,              // Projectiles et rayons tirés
+    pub hits: u32,               // Projectiles et rayons qui ont touché une cible
+    pub accuracy_bonus: i32,     // Bonus de précision
+    pub clear_bonus: i32,        // Bonus de fin de vague
+}
+
+impl WaveSummary {
+    /// Retourne la part des tirs qui ont touché une cible, entre 0 et 1 (0 sans aucun tir).
+    pub fn accuracy(&self) -> f32 {
+        if self.shots == 0 {
+            0.0
+        } else {
+            (self.hits as f32 / self.shots as f32).min(1.0)
+        }
+    }
+
+    /// Retourne le total des points gagnés pendant la vague, bonus compris.
+    pub fn total(&self) -> i32 {
+        self.destruction_points + self.accuracy_bonus + self.clear_bonus
+    }
+}
+
+/// Structure `World` qui représente l'état complet d'une partie.
+///
+/// Le monde possède les astéroïdes, le vaisseau, les missiles, les puits de gravité, les bonus,
+/// les soucoupes ennemies, le score et la vague courante. Il ne dépend d'aucun affichage :
+/// `main.rs` se contente de lui transmettre les commandes du joueur et de le dessiner.
+///
+/// Tout l'aléatoire de la partie provient d'un unique générateur initialisé à partir d'une graine :
+/// deux mondes créés avec la même graine et recevant les mêmes commandes évoluent à l'identique.
+pub struct World {
+    bounds: Vec2,                   // Dimensions de l'arène (largeur, hauteur)
+    spaceship: Spaceship,           // Vaisseau du joueur
+    asteroids: Vec<Asteroid>,       // Astéroïdes présents dans l'arène
+    missiles: Vec<Missile>,         // Missiles tirés par le vaisseau
+    wells: Vec<GravityWell>,        // Puits de gravité présents dans l'arène
+    beams: Vec<Beam>,               // Rayons laser encore affichés
+    powerups: Vec<PowerUp>,         // Bonus à ramasser
+    saucers: Vec<Saucer>,           // Soucoupes ennemies présentes dans l'arène
+    enemy_missiles: Vec<Missile>,   // Projectiles tirés par les soucoupes
+    saucer_timer: f32, // Délai restant avant l'arrivée de la prochaine soucoupe, en secondes
+    score: i32,        // Score actuel du joueur, jamais négatif
+    combo: u32,        // Coups au but consécutifs depuis le dernier tir manqué
+    wave_summary: WaveSummary, // Bilan de la vague en cours
+    last_wave: Option<WaveSummary>, // Bilan de la dernière vague terminée
+    lives: u32,        // Vies restantes, vaisseau en jeu compris
+    next_extra_life: i32, // Score à atteindre pour gagner la prochaine vie supplémentaire
+    level: u32,        // Numéro de la vague courante
+    waves: WaveConfig, // Progression des vagues
+    gameplay: GameplayConfig, // Réglages de jeu communs à toutes les vagues
+    invincibility_time: f32, // Durée d'invincibilité restante, en secondes
+    grace_time: f32,   // Durée restante de la période de grâce après réapparition, en secondes
+    wreck_time: Option<f32>, // Durée d'explosion restante tant que le vaisseau est détruit, en secondes
+    fire_cooldown: f32,      // Délai restant avant de pouvoir tirer à nouveau, en secondes
+    score_multiplier_time: f32, // Durée restante du multiplicateur de points, en secondes
+    slow_time: f32,          // Durée restante du ralenti des astéroïdes, en secondes
+    hyperspace_cooldown: f32, // Délai restant avant le prochain saut en hyperespace, en secondes
+    bash_time: f32,          // Durée d'affichage restante de l'onde de choc, en secondes
+    seed: u64,               // Graine du générateur aléatoire
+    rng: SmallRng,           // Générateur aléatoire de la partie
+    grid: UniformGrid,       // Grille des astéroïdes pour la détection des collisions
+}
+
+impl World {
+    /// Crée un nouveau monde avec le vaisseau au centre de l'arène.
+    ///
+    /// # Arguments
+    /// * `width` - Largeur de l'arène.
+    /// * `height` - Hauteur de l'arène.
+    /// * `waves` - Progression des vagues d'astéroïdes.
+    /// * `gameplay` - Réglages de jeu communs à toutes les vagues.
+    /// * `seed` - Graine du générateur aléatoire de la partie.
+    pub fn new(
+        width: f32,
+        height: f32,
+        waves: WaveConfig,
+        gameplay: GameplayConfig,
+        seed: u64,
+    ) -> Self {
+        let gameplay = gameplay.clamped();
+        let bounds = vec2(width, height);
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let wave = waves.wave(1);
+        let asteroids = Asteroid::generate_asteroid(bounds, &wave, &mut rng);
+        let wells = Self::generate_wells(bounds, &wave, &gameplay, &mut rng);
+        Self {
+            bounds,
+            spaceship: Spaceship::new(bounds / 2.0),
+            asteroids,
+            missiles: Vec::new(),
+            wells,
+            beams: Vec::new(),
+            powerups: Vec::new(),
+            saucers: Vec::new(),
+            enemy_missiles: Vec::new(),
+            saucer_timer: wave.saucer_interval,
+            score: 0,
+            combo: 0,
+            wave_summary: WaveSummary::default(),
+            last_wave: None,
+            lives: gameplay.initial_lives.max(1),
+            next_extra_life: gameplay.extra_life_score as i32,
+            level: 1,
+            waves,
+            gameplay,
+            invincibility_time: 0.0,
+            grace_time: 0.0,
+            wreck_time: None,
+            fire_cooldown: 0.0,
+            score_multiplier_time: 0.0,
+            slow_time: 0.0,
+            hyperspace_cooldown: 0.0,
+            bash_time: 0.0,
+            seed,
+            rng,
+            grid: UniformGrid::new(bounds, 2.0 * Asteroid::ASTEROID_TAILLE),
+        }
+    }
+
+    /// Retourne les dimensions de l'arène (largeur, hauteur).
+    pub fn bounds(&self) -> Vec2 {
+        self.bounds
+    }
+
+    /// Retourne la graine du générateur aléatoire de la partie.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Retourne le vaisseau du joueur.
+    pub fn spaceship(&self) -> &Spaceship {
+        &self.spaceship
+    }
+
+    /// Retourne les astéroïdes présents dans l'arène.
+    pub fn asteroids(&self) -> &[Asteroid] {
+        &self.asteroids
+    }
+
+    /// Retourne les missiles en vol.
+    pub fn missiles(&self) -> &[Missile] {
+        &self.missiles
+    }
+
+    /// Retourne les puits de gravité présents dans l'arène.
+    pub fn wells(&self) -> &[GravityWell] {
+        &self.wells
+    }
+
+    /// Retourne les rayons laser encore affichés.
+    pub fn beams(&self) -> &[Beam] {
+        &self.beams
+    }
+
+    /// Retourne les bonus à ramasser.
+    pub fn powerups(&self) -> &[PowerUp] {
+        &self.powerups
+    }
+
+    /// Retourne les soucoupes ennemies présentes dans l'arène.
+    pub fn saucers(&self) -> &[Saucer] {
+        &self.saucers
+    }
+
+    /// Retourne les projectiles tirés par les soucoupes.
+    pub fn enemy_missiles(&self) -> &[Missile] {
+        &self.enemy_missiles
+    }
+
+    /// Indique si le vaisseau est détruit et attend de réapparaître au centre de l'arène.
+    pub fn is_respawning(&self) -> bool {
+        self.wreck_time.is_some()
+    }
+
+    /// Retourne l'avancement de l'explosion du vaisseau, entre 0 et 1, ou `None` si aucune
+    /// explosion n'est en cours.
+    pub fn explosion_progress(&self) -> Option<f32> {
+        self.wreck_time
+            .filter(|time| *time > 0.0)
+            .map(|time| 1.0 - time / EXPLOSION_DURATION)
+    }
+
+    /// Retourne la durée restante de la période de grâce après réapparition, en secondes (0 si
+    /// elle est terminée).
+    pub fn grace_time(&self) -> f32 {
+        self.grace_time
+    }
+
+    /// Retourne la durée restante du multiplicateur de points, en secondes (0 s'il est inactif).
+    pub fn score_multiplier_time(&self) -> f32 {
+        self.score_multiplier_time
+    }
+
+    /// Retourne la durée restante du ralenti des astéroïdes, en secondes (0 s'il est inactif).
+    pub fn slow_time(&self) -> f32 {
+        self.slow_time
+    }
+
+    /// Retourne le délai restant avant le prochain saut en hyperespace, en secondes (0 s'il est
+    /// possible).
+    pub fn hyperspace_cooldown(&self) -> f32 {
+        self.hyperspace_cooldown
+    }
+
+    /// Retourne le rayon actuel de l'onde de choc du bouclier, qui s'étend autour du vaisseau
+    /// jusqu'à sa portée, ou `None` si aucune onde n'est affichée.
+    pub fn shockwave_radius(&self) -> Option<f32> {
+        (self.bash_time > 0.0)
+            .then(|| self.gameplay.bash_radius * (1.0 - self.bash_time / BASH_DURATION))
+    }
+
+    /// Retourne la fraction du délai entre deux tirs déjà écoulée, entre 0 et 1 : l'arme est
+    /// prête à tirer lorsqu'elle vaut 1.
+    pub fn weapon_readiness(&self) -> f32 {
+        let cooldown = self
+            .spaceship
+            .weapon()
+            .cooldown(self.gameplay.fire_cooldown);
+        if cooldown > 0.0 {
+            (1.0 - self.fire_cooldown / cooldown).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Retourne le score actuel du joueur.
+    pub fn score(&self) -> i32 {
+        self.score
+    }
+
+    /// Retourne le multiplicateur de combo, qui augmente avec les coups au but consécutifs.
+    pub fn combo_multiplier(&self) -> i32 {
+        (1 + (self.combo / COMBO_STEP) as i32).min(MAX_COMBO_MULTIPLIER)
+    }
+
+    /// Retourne le bilan de la dernière vague terminée, ou `None` pendant la première vague.
+    pub fn last_wave(&self) -> Option<&WaveSummary> {
+        self.last_wave.as_ref()
+    }
+
+    /// Retourne le nombre de vies restantes, vaisseau en jeu compris.
+    pub fn lives(&self) -> u32 {
+        self.lives
+    }
+
+    /// Retourne le numéro de la vague courante, à partir de 1.
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    /// Passe à la vague suivante : de nouveaux astéroïdes sont générés, les soucoupes et leurs
+    /// projectiles disparaissent, le vaisseau, son bouclier et le score sont conservés.
+    ///
+    /// Les bonus de précision et de fin de vague sont ajoutés au score, et le bilan de la vague
+    /// terminée est conservé pour l'affichage.
+    fn next_wave(&mut self) {
+        let mut summary = std::mem::take(&mut self.wave_summary);
+        summary.level = self.level;
+        summary.accuracy_bonus = (ACCURACY_BONUS * summary.accuracy()).round() as i32;
+        summary.clear_bonus = WAVE_CLEAR_BONUS * self.level as i32;
+        self.score += summary.accuracy_bonus + summary.clear_bonus;
+        self.last_wave = Some(summary);
+
+        self.level += 1;
+        let wave = self.waves.wave(self.level);
+        self.asteroids = Asteroid::generate_asteroid(self.bounds, &wave, &mut self.rng);
+        self.wells = Self::generate_wells(self.bounds, &wave, &self.gameplay, &mut self.rng);
+        self.saucers.clear();
+        self.enemy_missiles.clear();
+        self.saucer_timer = wave.saucer_interval;
+    }
+
+    /// Place les puits de gravité d'une nouvelle vague, loin du centre où se trouve le vaisseau.
+    fn generate_wells(
+        bounds: Vec2,
+        wave: &Wave,
+        gameplay: &GameplayConfig,
+        rng: &mut SmallRng,
+    ) -> Vec<GravityWell> {
+        (0..wave.wells)
+            .map(|_| {
+                GravityWell::new_alea(
+                    bounds,
+                    &[(bounds / 2.0, GravityWell::SAFE_DISTANCE)],
+                    gameplay.well_strength,
+                    gameplay.well_horizon,
+                    rng,
+                )
+            })
+            .collect()
+    }
+
+    /// Fait apparaître au hasard un puits de gravité en cours de vague, au rythme moyen de
+    /// `well_spawn_rate` puits par seconde, sans dépasser `max_wells`. Le puits évite le vaisseau
+    /// et la zone de réapparition au centre de l'arène.
+    fn spawn_random_well(&mut self) {
+        let wave = self.waves.wave(self.level);
+        if self.wells.len() < wave.max_wells
+            && self
+                .rng
+                .gen_bool((wave.well_spawn_rate * TIME_STEP).min(1.0) as f64)
+        {
+            let avoid = [
+                (self.spaceship.position(), GravityWell::SAFE_DISTANCE),
+                (
+                    self.bounds / 2.0,
+                    self.gameplay.respawn_radius + self.gameplay.well_horizon,
+                ),
+            ];
+            self.wells.push(GravityWell::new_alea(
+                self.bounds,
+                &avoid,
+                self.gameplay.well_strength,
+                self.gameplay.well_horizon,
+                &mut self.rng,
+            ));
+        }
+    }
+
+    /// Fait entrer une soucoupe toutes les `saucer_interval` secondes lorsqu'aucune n'est présente,
+    /// puis fait manœuvrer et tirer les soucoupes, qui disparaissent une fois l'arène traversée.
+    ///
+    /// La petite soucoupe, plus dangereuse, est d'autant plus fréquente que la vague est avancée.
+    fn update_saucers(&mut self) {
+        let wave = self.waves.wave(self.level);
+        if wave.saucer_interval > 0.0 && self.saucers.is_empty() {
+            self.saucer_timer -= TIME_STEP;
+            if self.saucer_timer <= 0.0 {
+                self.saucer_timer = wave.saucer_interval;
+                let size = if self.rng.gen_bool(wave.small_saucer_chance as f64) {
+                    SaucerSize::Small
+                } else {
+                    SaucerSize::Large
+                };
+                self.saucers
+                    .push(Saucer::enter(size, self.bounds, &mut self.rng));
+            }
+        }
+
+        let target = self.spaceship.position();
+        for saucer in self.saucers.iter_mut() {
+            saucer.steer(TIME_STEP, &mut self.rng);
+            self.enemy_missiles.extend(saucer.fire(
+                target,
+                wave.saucer_accuracy,
+                self.bounds,
+                TIME_STEP,
+                &mut self.rng,
+            ));
+            saucer.update_position(self.bounds, TIME_STEP);
+        }
+        self.saucers.retain(|saucer| !saucer.has_left());
+    }
+
+    /// Applique l'attraction des puits de gravité au vaisseau, aux missiles et aux astéroïdes.
+    fn apply_gravity(&mut self) {
+        let bounds = self.bounds;
+        for well in &self.wells {
+            if self.wreck_time.is_none() {
+                self.spaceship.accelerate(
+                    well.acceleration_at(self.spaceship.position(), bounds),
+                    TIME_STEP,
+                );
+            }
+            for missile in self.missiles.iter_mut() {
+                missile.accelerate(well.acceleration_at(missile.position(), bounds), TIME_STEP);
+            }
+            for missile in self.enemy_missiles.iter_mut() {
+                missile.accelerate(well.acceleration_at(missile.position(), bounds), TIME_STEP);
+            }
+            for asteroid in self.asteroids.iter_mut() {
+                asteroid.accelerate(well.acceleration_at(asteroid.position(), bounds), TIME_STEP);
+            }
+        }
+    }
+
+    /// Retourne les points rapportés par la destruction d'un astéroïde ou d'une soucoupe,
+    /// multiplicateurs de combo et de bonus compris.
+    fn points(&self, base: i32) -> i32 {
+        let points = base * self.combo_multiplier();
+        if self.score_multiplier_time > 0.0 {
+            points * SCORE_MULTIPLIER
+        } else {
+            points
+        }
+    }
+
+    /// Ajoute au score les points d'une destruction et prolonge le combo.
+    ///
+    /// # Arguments
+    /// * `base` - Les points de base de l'astéroïde ou de la soucoupe détruit.
+    fn score_hit(&mut self, base: i32) {
+        let points = self.points(base);
+        self.score += points;
+        self.wave_summary.destruction_points += points;
+        self.combo += 1;
+        self.wave_summary.best_combo = self.wave_summary.best_combo.max(self.combo);
+    }
+
+    /// Applique l'effet d'un bonus ramassé par le vaisseau.
+    fn apply_powerup(&mut self, kind: PowerUpKind) {
+        match kind {
+            PowerUpKind::ShieldRecharge => self.spaceship.shield = Spaceship::MAX_SHIELD,
+            PowerUpKind::WeaponUpgrade => self.spaceship.upgrade_weapon(),
+            PowerUpKind::Invincibility => {
+                self.spaceship.invincible = true;
+                self.invincibility_time = self.invincibility_time.max(POWERUP_DURATION);
+            }
+            PowerUpKind::ScoreMultiplier => self.score_multiplier_time = POWERUP_DURATION,
+            PowerUpKind::SlowTime => self.slow_time = POWERUP_DURATION,
+        }
+    }
+
+    /// Fait avancer la simulation d'un pas de durée fixe `TIME_STEP`.
+    ///
+    /// Gère l'invincibilité, la réapparition du vaisseau détruit, les chocs entre astéroïdes, les
+    /// collisions, les commandes du joueur, l'attraction des puits de gravité puis le déplacement
+    /// des objets.
+    /// Lorsque tous les astéroïdes sont détruits, la vague suivante est générée.
+    ///
+    /// # Arguments
+    /// * `input` - Les commandes du joueur pour ce pas.
+    ///
+    /// # Retourne
+    /// * `WorldStatus` - L'état de la partie après ce pas.
+    pub fn step(&mut self, input: &PlayerInput) -> WorldStatus {
+        self.invincibility_time = (self.invincibility_time - TIME_STEP).max(0.0);
+        self.grace_time = (self.grace_time - TIME_STEP).max(0.0);
+        self.spaceship.invincible = self.invincibility_time > 0.0 || self.grace_time > 0.0;
+        self.score_multiplier_time = (self.score_multiplier_time - TIME_STEP).max(0.0);
+        self.slow_time = (self.slow_time - TIME_STEP).max(0.0);
+        self.hyperspace_cooldown = (self.hyperspace_cooldown - TIME_STEP).max(0.0);
+        self.bash_time = (self.bash_time - TIME_STEP).max(0.0);
+
+        self.bounce_asteroids();
+        let damage = self.check_collisions() + self.check_saucer_collisions();
+        if damage > 0.0 && self.damage_spaceship(damage) {
+            return WorldStatus::Defeat;
+        }
+        self.award_extra_lives();
+
+        if self.wreck_time.is_some() {
+            self.try_respawn();
+        } else if self.apply_input(input) && self.destroy_spaceship() {
+            return WorldStatus::Defeat;
+        } else {
+            let regen = self.gameplay.shield_regen;
+            self.spaceship.recharge(regen * TIME_STEP);
+        }
+        self.apply_gravity();
+        self.update_positions();
+        self.update_saucers();
+        self.spawn_random_well();
+
+        if self.asteroids.is_empty() {
+            self.next_wave(); // Vague suivante si plus d'astéroïdes
+            WorldStatus::WaveCleared
+        } else {
+            WorldStatus::Running
+        }
+    }
+
+    /// Calcule les dégâts d'une collision entre le vaisseau et un objet, proportionnels à la
+    /// taille de l'objet et croissants avec la vitesse du choc.
+    ///
+    /// # Arguments
+    /// * `gameplay` - Les réglages de jeu, qui fixent les dégâts par pixel de rayon.
+    /// * `radius` - Le rayon de l'objet percuté.
+    /// * `relative_velocity` - La vitesse de l'objet percuté par rapport au vaisseau.
+    fn collision_damage(gameplay: &GameplayConfig, radius: f32, relative_velocity: Vec2) -> f32 {
+        gameplay.collision_damage
+            * radius
+            * (1.0 + relative_velocity.length() / IMPACT_REFERENCE_SPEED)
+    }
+
+    /// Fait encaisser des dégâts au bouclier du vaisseau. S'il tient, le vaisseau est brièvement
+    /// invincible, sinon il est détruit.
+    ///
+    /// # Arguments
+    /// * `damage` - L'énergie retirée au bouclier (`f32::INFINITY` pour un coup fatal).
+    ///
+    /// # Retourne
+    /// * un booléen - `true` si le vaisseau détruit était le dernier et la partie est perdue.
+    fn damage_spaceship(&mut self, damage: f32) -> bool {
+        if self.spaceship.absorb(damage) {
+            self.invincibility_time = self.invincibility_time.max(HIT_INVINCIBILITY);
+            self.spaceship.invincible = true;
+            false
+        } else {
+            self.destroy_spaceship()
+        }
+    }
+
+    /// Détruit le vaisseau : il explose et perd une vie, puis réapparaîtra au centre de l'arène
+    /// (voir `try_respawn`).
+    ///
+    /// # Retourne
+    /// * un booléen - `true` si le vaisseau n'avait plus de vie et la partie est perdue.
+    fn destroy_spaceship(&mut self) -> bool {
+        self.lives -= 1;
+        if self.lives == 0 {
+            return true;
+        }
+        self.spaceship.shield = 0.0;
+        self.wreck_time = Some(EXPLOSION_DURATION);
+        self.invincibility_time = 0.0;
+        self.spaceship.invincible = false;
+        false
+    }
+
+    /// Fait avancer l'explosion du vaisseau détruit, puis le fait réapparaître au centre de
+    /// l'arène, à l'arrêt et le bouclier chargé, dès qu'aucun astéroïde, soucoupe ni puits ne se
+    /// trouve à moins de `respawn_radius`. Le vaisseau est ensuite invincible pendant la période
+    /// de grâce.
+    fn try_respawn(&mut self) {
+        let Some(time) = self.wreck_time.as_mut() else {
+            return;
+        };
+        *time = (*time - TIME_STEP).max(0.0);
+        if *time > 0.0 {
+            return;
+        }
+
+        let gameplay = self.gameplay;
+        let center = self.bounds / 2.0;
+        let clear = |object: &dyn StellarObject| {
+            wrapped_delta(center, object.position(), self.bounds).length() - object.radius()
+                >= gameplay.respawn_radius
+        };
+        if self.asteroids.iter().all(|asteroid| clear(asteroid))
+            && self.saucers.iter().all(|saucer| clear(saucer))
+            && self.wells.iter().all(|well| clear(well))
+        {
+            self.wreck_time = None;
+            self.spaceship.teleport(center);
+            self.spaceship.shield = Spaceship::MAX_SHIELD;
+            self.grace_time = gameplay.respawn_grace;
+            self.spaceship.invincible = self.grace_time > 0.0;
+        }
+    }
+
+    /// Applique les commandes du joueur au vaisseau, change d'arme et tire si demandé, à condition
+    /// que le délai de l'arme depuis le dernier tir soit écoulé et que trop de missiles ne soient
+    /// pas déjà en vol. Déclenche aussi le saut en hyperespace et l'onde de choc du bouclier.
+    ///
+    /// # Retourne
+    /// * un booléen - `true` si un saut en hyperespace raté a touché le vaisseau.
+    fn apply_input(&mut self, input: &PlayerInput) -> bool {
+        if input.turn_right {
+            self.spaceship.turn_right(TIME_STEP);
+        }
+        if input.turn_left {
+            self.spaceship.turn_left(TIME_STEP);
+        }
+        if let Some(heading) = input.aim_heading() {
+            if !input.turn_left && !input.turn_right {
+                self.spaceship.turn_towards(heading, TIME_STEP);
+            }
+        }
+        if input.thrust {
+            self.spaceship.apply_thrust(THRUST);
+        } else if input.throttle > 0 {
+            self.spaceship
+                .apply_thrust(THRUST * input.throttle as f32 / u8::MAX as f32);
+        } else if input.reverse {
+            self.spaceship.apply_thrust(REVERSE_THRUST);
+        } else {
+            self.spaceship.apply_thrust(0.0);
+        }
+        if input.switch_weapon {
+            self.spaceship.next_weapon();
+        }
+        if input.shield_bash {
+            self.shield_bash();
+        }
+        let jump_failed = input.hyperspace && self.hyperspace();
+        self.fire(input);
+        jump_failed
+    }
+
+    /// Accorde une vie supplémentaire chaque fois que le score franchit un multiple de
+    /// `extra_life_score`.
+    fn award_extra_lives(&mut self) {
+        let step = self.gameplay.extra_life_score as i32;
+        while step > 0 && self.score >= self.next_extra_life {
+            self.lives += 1;
+            self.next_extra_life += step;
+        }
+    }
+
+    /// Fait sauter le vaisseau en hyperespace vers une position aléatoire de l'arène, qui peut
+    /// se trouver sur la trajectoire d'un astéroïde. Le saut n'est possible qu'une fois le délai
+    /// depuis le précédent écoulé.
+    ///
+    /// # Retourne
+    /// * un booléen - `true` si le saut a échoué et détruit le vaisseau (sauf s'il est invincible).
+    fn hyperspace(&mut self) -> bool {
+        if self.hyperspace_cooldown > 0.0 {
+            return false;
+        }
+        self.hyperspace_cooldown = self.gameplay.hyperspace_cooldown;
+        let destination = vec2(
+            self.rng.gen_range(0.0..self.bounds.x),
+            self.rng.gen_range(0.0..self.bounds.y),
+        );
+        self.spaceship.teleport(destination);
+        self.rng.gen_bool(self.gameplay.hyperspace_failure as f64) && !self.spaceship.invincible
+    }
+
+    /// Consomme de l'énergie du bouclier pour repousser les astéroïdes proches du vaisseau. Si le
+    /// bouclier n'a pas assez d'énergie, l'onde de choc ne se déclenche pas.
+    fn shield_bash(&mut self) {
+        let cost = self.gameplay.bash_cost;
+        if self.spaceship.shield < cost {
+            return;
+        }
+        self.spaceship.shield -= cost;
+        self.bash_time = BASH_DURATION;
+        let center = self.spaceship.position();
+        for asteroid in self.asteroids.iter_mut() {
+            let away = wrapped_delta(center, asteroid.position(), self.bounds);
+            if away.length() - asteroid.radius() < self.gameplay.bash_radius {
+                let direction = away.try_normalize().unwrap_or(Vec2::X);
+                asteroid.accelerate(direction * self.gameplay.bash_speed, 1.0);
+            }
+        }
+    }
+
+    /// Tire avec l'arme sélectionnée si demandé (ou tant que la gâchette est maintenue pour une
+    /// arme automatique), à condition que le délai de l'arme depuis le dernier tir soit écoulé et
+    /// que trop de missiles ne soient pas déjà en vol.
+    fn fire(&mut self, input: &PlayerInput) {
+        // Tir limité par le délai entre deux tirs et le nombre de missiles en vol
+        self.fire_cooldown = (self.fire_cooldown - TIME_STEP).max(0.0);
+        let weapon = self.spaceship.weapon();
+        let fire = input.fire || (input.fire_held && weapon.is_automatic());
+        if !fire || self.fire_cooldown > 0.0 {
+            return;
+        }
+        let gameplay = self.gameplay;
+        let angle = self.spaceship.angle();
+        let muzzle = arena::wrap_position(
+            self.spaceship.position() + Vec2::from_angle(angle) * self.spaceship.radius(),
+            self.bounds,
+        );
+        if weapon == Weapon::Laser {
+            self.fire_laser(Beam::new(muzzle, angle));
+        } else {
+            let missiles = weapon.missiles(
+                muzzle,
+                angle,
+                self.spaceship.speed(),
+                gameplay.missile_lifetime,
+            );
+            if self.missiles.len() + missiles.len() > weapon.max_missiles(gameplay.max_missiles) {
+                return;
+            }
+            self.wave_summary.shots += missiles.len() as u32;
+            self.missiles.extend(missiles);
+        }
+        self.fire_cooldown = weapon.cooldown(gameplay.fire_cooldown);
+    }
+
+    /// Détruit et divise tous les astéroïdes traversés par un rayon laser, détruit les soucoupes
+    /// traversées, puis garde le rayon pour l'affichage.
+    fn fire_laser(&mut self, beam: Beam) {
+        let bounds = self.bounds;
+        let mut targets = Vec::new();
+        let mut split_asteroids = Vec::new();
+        self.asteroids
+            .retain(|asteroid| match beam.hit(asteroid, bounds) {
+                Some(point) => {
+                    targets.push(asteroid.points());
+                    self.powerups.extend(PowerUp::maybe_drop(
+                        asteroid.position(),
+                        asteroid.speed(),
+                        self.gameplay.powerup_chance,
+                        self.gameplay.powerup_lifetime,
+                        &mut self.rng,
+                    ));
+                    let impact = Impact {
+                        point,
+                        velocity: beam.direction() * Missile::MISSILE_VITESSE,
+                    };
+                    split_asteroids.extend(asteroid.split(
+                        &impact,
+                        self.gameplay.large_fragments,
+                        self.waves.max_speed,
+                        bounds,
+                        &mut self.rng,
+                    ));
+                    false
+                }
+                None => true,
+            });
+        self.asteroids.append(&mut split_asteroids);
+        self.saucers
+            .retain(|saucer| match beam.hit(saucer, bounds) {
+                Some(_) => {
+                    targets.push(saucer.size().points());
+                    false
+                }
+                None => true,
+            });
+
+        // Un rayon qui ne touche rien est un tir manqué.
+        self.wave_summary.shots += 1;
+        if targets.is_empty() {
+            self.combo = 0;
+        } else {
+            self.wave_summary.hits += 1;
+        }
+        for base in targets {
+            self.score_hit(base);
+        }
+        self.beams.push(beam);
+    }
+
+    /// Met à jour les positions des astéroïdes, du vaisseau, des missiles et des bonus, puis retire
+    /// les missiles et les bonus arrivés en fin de vie.
+    fn update_positions(&mut self) {
+        let asteroid_step = if self.slow_time > 0.0 {
+            TIME_STEP * SLOW_TIME_FACTOR
+        } else {
+            TIME_STEP
+        };
+        for asteroid in self.asteroids.iter_mut() {
+            asteroid.update_position(self.bounds, asteroid_step);
+        }
+
+        if self.wreck_time.is_none() {
+            self.spaceship.update_position(self.bounds, TIME_STEP);
+        }
+
+        // Les missiles guidés tournent vers l'astéroïde le plus proche.
+        let bounds = self.bounds;
+        for missile in self
+            .missiles
+            .iter_mut()
+            .filter(|missile| missile.is_homing())
+        {
+            let nearest = self
+                .asteroids
+                .iter()
+                .map(|asteroid| wrapped_delta(missile.position(), asteroid.position(), bounds))
+                .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()));
+            if let Some(to_target) = nearest {
+                missile.steer_towards(to_target, TIME_STEP);
+            }
+        }
+
+        for missile in self.missiles.iter_mut() {
+            missile.update_position(self.bounds, TIME_STEP);
+        }
+        // Un missile arrivé en fin de vie sans rien toucher est un tir manqué.
+        if self.missiles.iter().any(|missile| missile.is_expired()) {
+            self.combo = 0;
+        }
+        self.missiles.retain(|missile| !missile.is_expired());
+        for missile in self.enemy_missiles.iter_mut() {
+            missile.update_position(self.bounds, TIME_STEP);
+        }
+        self.enemy_missiles.retain(|missile| !missile.is_expired());
+        self.beams.retain_mut(|beam| beam.tick(TIME_STEP));
+
+        for powerup in self.powerups.iter_mut() {
+            powerup.update_position(self.bounds, TIME_STEP);
+        }
+        self.powerups.retain(|powerup| !powerup.is_expired());
+    }
+
+    /// Fait rebondir les astéroïdes qui se touchent les uns sur les autres, avec le coefficient de
+    /// restitution des réglages de jeu.
+    fn bounce_asteroids(&mut self) {
+        let bounds = self.bounds;
+        let restitution = self.gameplay.restitution;
+        self.grid.rebuild(&self.asteroids);
+        let pairs = self
+            .grid
+            .find_pairs(&self.asteroids, &self.asteroids, |a, b| {
+                a.check_collision(b, bounds)
+            });
+        for (i, j) in pairs.into_iter().filter(|(i, j)| i < j) {
+            let (left, right) = self.asteroids.split_at_mut(j);
+            left[i].bounce(&mut right[0], restitution, bounds);
+        }
+    }
+
+    /// Vérifie les collisions entre les objets du jeu (vaisseau, astéroïdes, missiles, puits).
+    ///
+    /// # Retourne
+    /// * `f32` - Les dégâts subis par le vaisseau s'il entre en collision avec des astéroïdes
+    ///   alors qu'il n'est pas invincible, infinis s'il est avalé par un puits.
+    ///
+    /// Les objets qui franchissent l'horizon d'un puits disparaissent, sans rapporter de points.
+    /// Le vaisseau invincible avalé est rejeté à l'opposé du puits dans l'arène, à l'arrêt. Le
+    /// vaisseau détruit, en attente de réapparition, ne touche rien.
+    ///
+    /// Les astéroïdes sont d'abord répartis dans une grille uniforme : chaque objet n'est comparé
+    /// qu'aux astéroïdes des cellules qu'il recouvre.
+    fn check_collisions(&mut self) -> f32 {
+        let bounds = self.bounds;
+        let large_fragments = self.gameplay.large_fragments;
+        self.grid.rebuild(&self.asteroids);
+        let mut destroyed = vec![false; self.asteroids.len()];
+        let mut split_asteroids = Vec::new();
+        let mut damage = 0.0;
+        let present = self.wreck_time.is_none();
+
+        // Détecte les collisions entre le vaisseau et les astéroïdes.
+        let mut candidates = Vec::new();
+        if present {
+            self.grid.query(
+                self.spaceship.position(),
+                self.spaceship.radius(),
+                &mut candidates,
+            );
+        }
+        for index in candidates {
+            let asteroid = &self.asteroids[index];
+            if asteroid.check_collision(&self.spaceship, bounds) {
+                destroyed[index] = true; // Supprimer l'astéroïde touché
+                let impact = Impact {
+                    point: self.spaceship.position(),
+                    velocity: self.spaceship.speed(),
+                };
+                split_asteroids.extend(asteroid.split(
+                    &impact,
+                    large_fragments,
+                    self.waves.max_speed,
+                    bounds,
+                    &mut self.rng,
+                ));
+                if !self.spaceship.invincible {
+                    self.score = (self.score - COLLISION_PENALTY).max(0); // Le score ne devient jamais négatif
+                    damage += Self::collision_damage(
+                        &self.gameplay,
+                        asteroid.radius(),
+                        asteroid.speed() - self.spaceship.speed(),
+                    );
+                }
+            }
+        }
+
+        // Vérification des collisions entre missiles et astéroïdes
+        let mut missile_hit = vec![false; self.missiles.len()];
+        let hits = self
+            .grid
+            .find_pairs(&self.missiles, &self.asteroids, |missile, asteroid| {
+                wrapped_delta(missile.position(), asteroid.position(), bounds).length()
+                    < asteroid.radius()
+            });
+        for (missile_index, asteroid_index) in hits {
+            // Un missile ne détruit qu'un seul astéroïde, même s'il en chevauche plusieurs.
+            if !destroyed[asteroid_index] && !missile_hit[missile_index] {
+                destroyed[asteroid_index] = true; // Supprimer l'astéroïde touché
+                missile_hit[missile_index] = true;
+                self.wave_summary.hits += 1;
+                self.score_hit(self.asteroids[asteroid_index].points());
+                let asteroid = &self.asteroids[asteroid_index];
+                self.powerups.extend(PowerUp::maybe_drop(
+                    asteroid.position(),
+                    asteroid.speed(),
+                    self.gameplay.powerup_chance,
+                    self.gameplay.powerup_lifetime,
+                    &mut self.rng,
+                ));
+                let missile = &self.missiles[missile_index];
+                let impact = Impact {
+                    point: missile.position(),
+                    velocity: missile.velocity(),
+                };
+                // Ajouter les astéroïdes créés par la division
+                split_asteroids.extend(self.asteroids[asteroid_index].split(
+                    &impact,
+                    large_fragments,
+                    self.waves.max_speed,
+                    bounds,
+                    &mut self.rng,
+                ));
+            }
+        }
+
+        // Bonus ramassés par le vaisseau
+        let mut collected = Vec::new();
+        self.powerups.retain(|powerup| {
+            let touched = present && powerup.check_collision(&self.spaceship, bounds);
+            if touched {
+                collected.push(powerup.kind());
+            }
+            !touched
+        });
+        for kind in collected {
+            self.apply_powerup(kind);
+        }
+
+        // Objets avalés par les puits de gravité
+        for (index, asteroid) in self.asteroids.iter().enumerate() {
+            if self
+                .wells
+                .iter()
+                .any(|well| well.swallows(asteroid, bounds))
+            {
+                destroyed[index] = true;
+            }
+        }
+        for (index, missile) in self.missiles.iter().enumerate() {
+            if !missile_hit[index] && self.wells.iter().any(|well| well.swallows(missile, bounds)) {
+                missile_hit[index] = true;
+                self.combo = 0; // Tir manqué
+            }
+        }
+        if let Some(well) = self
+            .wells
+            .iter()
+            .find(|well| present && well.swallows(&self.spaceship, bounds))
+        {
+            if self.spaceship.invincible {
+                let antipode = arena::wrap_position(well.position() + bounds / 2.0, bounds);
+                self.spaceship.teleport(antipode);
+            } else {
+                damage = f32::INFINITY;
+            }
+        }
+
+        let mut missile_index = 0;
+        self.missiles.retain(|_| {
+            let keep = !missile_hit[missile_index];
+            missile_index += 1;
+            keep
+        });
+        let mut asteroid_index = 0;
+        self.asteroids.retain(|_| {
+            let keep = !destroyed[asteroid_index];
+            asteroid_index += 1;
+            keep
+        });
+
+        // Ajouter les nouveaux astéroïdes créés par la division
+        self.asteroids.append(&mut split_asteroids);
+
+        damage
+    }
+
+    /// Vérifie les collisions impliquant les soucoupes et leurs projectiles.
+    ///
+    /// # Retourne
+    /// * `f32` - Les dégâts subis par le vaisseau s'il est touché par des projectiles ennemis ou
+    ///   percute une soucoupe alors qu'il n'est pas invincible.
+    ///
+    /// Une soucoupe abattue par un missile rapporte des points. Les projectiles ennemis détruisent
+    /// aussi les astéroïdes qu'ils touchent, sans rapporter de points au joueur. Une soucoupe qui
+    /// percute un astéroïde ou le vaisseau est détruite avec lui. Les puits avalent soucoupes et
+    /// projectiles ennemis.
+    fn check_saucer_collisions(&mut self) -> f32 {
+        let bounds = self.bounds;
+        let large_fragments = self.gameplay.large_fragments;
+        let max_speed = self.waves.max_speed;
+        let bullet_damage = self.gameplay.bullet_damage;
+        let mut destroyed = vec![false; self.asteroids.len()];
+        let mut split_asteroids = Vec::new();
+        let mut damage = 0.0;
+        let present = self.wreck_time.is_none();
+
+        let mut split = |asteroid: &Asteroid, impact: Impact, rng: &mut SmallRng| {
+            split_asteroids.extend(asteroid.split(
+                &impact,
+                large_fragments,
+                max_speed,
+                bounds,
+                rng,
+            ));
+        };
+
+        // Soucoupes abattues par les missiles du joueur
+        let mut targets = Vec::new();
+        self.saucers.retain(|saucer| {
+            match self
+                .missiles
+                .iter()
+                .position(|missile| missile.check_collision(saucer, bounds))
+            {
+                Some(index) => {
+                    self.missiles.swap_remove(index);
+                    targets.push(saucer.size().points());
+                    false
+                }
+                None => true,
+            }
+        });
+        for base in targets {
+            self.wave_summary.hits += 1;
+            self.score_hit(base);
+        }
+
+        // Projectiles ennemis contre le vaisseau et les astéroïdes
+        self.grid.rebuild(&self.asteroids);
+        let hits = self.grid.find_pairs(
+            &self.enemy_missiles,
+            &self.asteroids,
+            |missile, asteroid| {
+                wrapped_delta(missile.position(), asteroid.position(), bounds).length()
+                    < asteroid.radius()
+            },
+        );
+        let mut missile_hit = vec![false; self.enemy_missiles.len()];
+        for (missile_index, asteroid_index) in hits {
+            if !destroyed[asteroid_index] && !missile_hit[missile_index] {
+                destroyed[asteroid_index] = true;
+                missile_hit[missile_index] = true;
+                let missile = &self.enemy_missiles[missile_index];
+                let impact = Impact {
+                    point: missile.position(),
+                    velocity: missile.velocity(),
+                };
+                split(&self.asteroids[asteroid_index], impact, &mut self.rng);
+            }
+        }
+        for (index, missile) in self.enemy_missiles.iter().enumerate() {
+            if present && missile.check_collision(&self.spaceship, bounds) {
+                missile_hit[index] = true;
+                if !self.spaceship.invincible {
+                    damage += bullet_damage;
+                }
+            } else if self.wells.iter().any(|well| well.swallows(missile, bounds)) {
+                missile_hit[index] = true;
+            }
+        }
+        let mut missile_index = 0;
+        self.enemy_missiles.retain(|_| {
+            let keep = !missile_hit[missile_index];
+            missile_index += 1;
+            keep
+        });
+
+        // Soucoupes percutant le vaisseau ou un astéroïde, ou avalées par un puits
+        self.saucers.retain(|saucer| {
+            if present && saucer.check_collision(&self.spaceship, bounds) {
+                if !self.spaceship.invincible {
+                    damage += Self::collision_damage(
+                        &self.gameplay,
+                        saucer.radius(),
+                        saucer.velocity() - self.spaceship.speed(),
+                    );
+                }
+                return false;
+            }
+            let mut candidates = Vec::new();
+            self.grid
+                .query(saucer.position(), saucer.radius(), &mut candidates);
+            if let Some(index) = candidates.into_iter().find(|&index| {
+                !destroyed[index] && self.asteroids[index].check_collision(saucer, bounds)
+            }) {
+                destroyed[index] = true;
+                let impact = Impact {
+                    point: saucer.position(),
+                    velocity: Vec2::ZERO,
+                };
+                split(&self.asteroids[index], impact, &mut self.rng);
+                return false;
+            }
+            !self.wells.iter().any(|well| well.swallows(saucer, bounds))
+        });
+
+        let mut asteroid_index = 0;
+        self.asteroids.retain(|_| {
+            let keep = !destroyed[asteroid_index];
+            asteroid_index += 1;
+            keep
+        });
+        self.asteroids.append(&mut split_asteroids);
+
+        damage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Crée un monde de 800 x 600 sans puits de gravité, peuplé des astéroïdes donnés.
+    fn test_world(asteroids: Vec<Asteroid>) -> World {
+        let waves = WaveConfig {
+            wells_start_level: 0,
+            ..Default::default()
+        };
+        let mut world = World::new(800.0, 600.0, waves, GameplayConfig::default(), 42);
+        world.asteroids = asteroids;
+        world
+    }
+
+    #[test]
+    fn test_world_runs_headless() {
+        let mut world = World::new(
+            800.0,
+            600.0,
+            WaveConfig::default(),
+            GameplayConfig::default(),
+            42,
+        );
+        let input = PlayerInput {
+            thrust: true,
+            fire: true,
+            ..Default::default()
+        };
+
+        for _ in 0..120 {
+            if world.step(&input) != WorldStatus::Running {
+                break;
+            }
+            let position = world.spaceship().position();
+            assert!(position.x >= 0.0 && position.x <= 800.0);
+            assert!(position.y >= 0.0 && position.y <= 600.0);
+        }
+    }
+
+    #[test]
+    fn test_cleared_field_starts_next_wave() {
+        let waves = WaveConfig {
+            initial_count: 0,
+            ..Default::default()
+        };
+        let mut world = World::new(800.0, 600.0, waves, GameplayConfig::default(), 42);
+        world.score = 50;
+
+        assert_eq!(
+            world.step(&PlayerInput::default()),
+            WorldStatus::WaveCleared
+        );
+        assert_eq!(world.level(), 2);
+        assert_eq!(world.asteroids().len(), waves.wave(2).count);
+        // Sans aucun tir, seul le bonus de fin de vague s'ajoute au score.
+        let summary = world.last_wave().unwrap();
+        assert_eq!((summary.level, summary.accuracy_bonus), (1, 0));
+        assert_eq!(world.score(), 50 + WAVE_CLEAR_BONUS);
+        assert_eq!(world.spaceship().shield, Spaceship::MAX_SHIELD);
+        assert_eq!(world.lives(), world.gameplay.initial_lives);
+    }
+
+    #[test]
+    fn test_gravity_well_attracts_and_swallows() {
+        let mut world = World::new(
+            800.0,
+            600.0,
+            WaveConfig::default(),
+            GameplayConfig::default(),
+            42,
+        );
+        let target = vec2(100.0, 100.0);
+        let far = vec2(250.0, 100.0);
+        world.asteroids = vec![
+            Asteroid::new_at(target, 15.0, Vec2::ZERO),
+            Asteroid::new_at(far, 15.0, Vec2::ZERO),
+        ];
+        world.wells = vec![GravityWell::new(target, 400_000.0, 25.0)];
+
+        world.step(&PlayerInput::default());
+
+        // L'astéroïde au centre du puits est avalé, l'autre est attiré vers lui.
+        assert_eq!(world.asteroids().len(), 1);
+        for _ in 0..30 {
+            world.step(&PlayerInput::default());
+        }
+        let asteroid = &world.asteroids()[0];
+        assert!(asteroid.position().x < far.x);
+        assert_eq!(asteroid.position().y, far.y);
+    }
+
+    #[test]
+    fn test_swallowed_ship_is_destroyed_unless_invincible() {
+        let mut world = World::new(
+            800.0,
+            600.0,
+            WaveConfig::default(),
+            GameplayConfig::default(),
+            42,
+        );
+        world.asteroids = vec![Asteroid::new_at(vec2(50.0, 50.0), 15.0, Vec2::ZERO)];
+        let center = world.spaceship().position();
+        world.wells = vec![GravityWell::new(center, 400_000.0, 25.0)];
+
+        assert_eq!(world.step(&PlayerInput::default()), WorldStatus::Running);
+        assert_eq!(world.lives(), 2);
+        assert!(world.is_respawning());
+
+        // Invincible, le vaisseau est rejeté à l'opposé du puits.
+        world.wreck_time = None;
+        world.spaceship.teleport(center);
+        world.invincibility_time = 1.0;
+        world.step(&PlayerInput::default());
+        assert_eq!(world.lives(), 2);
+        let distance = wrapped_delta(world.spaceship().position(), center, world.bounds);
+        assert!(distance.length() > 300.0);
+    }
+
+    #[test]
+    fn test_destroyed_ship_respawns_when_center_is_clear() {
+        let center = vec2(400.0, 300.0);
+        let mut world = test_world(vec![
+            Asteroid::new_at(center, 15.0, Vec2::ZERO),
+            Asteroid::new_at(vec2(50.0, 50.0), 15.0, Vec2::ZERO),
+        ]);
+        assert_eq!(world.spaceship().position(), center);
+        world.spaceship.shield = 0.0;
+        world.step(&PlayerInput::default());
+        assert!(world.is_respawning());
+        assert_eq!(world.lives(), 2);
+
+        // Un astéroïde au centre empêche la réapparition, même après l'explosion.
+        world.asteroids[0] = Asteroid::new_at(center + vec2(40.0, 0.0), 15.0, Vec2::ZERO);
+        let explosion_steps = (EXPLOSION_DURATION / TIME_STEP).round() as usize + 1;
+        for _ in 0..explosion_steps {
+            world.step(&PlayerInput::default());
+        }
+        assert!(world.is_respawning());
+        assert!(world.explosion_progress().is_none());
+
+        // Une fois le centre dégagé, le vaisseau réapparaît, invincible pendant la période de grâce.
+        world.asteroids[0] = Asteroid::new_at(vec2(700.0, 50.0), 15.0, Vec2::ZERO);
+        world.step(&PlayerInput::default());
+        assert!(!world.is_respawning());
+        assert_eq!(world.spaceship().position(), center);
+        assert!(world.spaceship().invincible);
+        assert_eq!(world.spaceship().shield, Spaceship::MAX_SHIELD);
+        let grace_steps = (world.gameplay.respawn_grace / TIME_STEP).round() as usize + 1;
+        for _ in 0..grace_steps {
+            world.step(&PlayerInput::default());
+        }
+        assert!(!world.spaceship().invincible);
+    }
+
+    #[test]
+    fn test_fire_rate_and_missile_lifetime_are_limited() {
+        let mut world = test_world(vec![Asteroid::new_at(vec2(50.0, 50.0), 15.0, Vec2::ZERO)]);
+        let fire = PlayerInput {
+            fire: true,
+            ..Default::default()
+        };
+
+        // Deux tirs consécutifs sont séparés par le délai entre deux tirs.
+        world.step(&fire);
+        world.step(&fire);
+        assert_eq!(world.missiles().len(), 1);
+
+        // Jamais plus de `max_missiles` missiles en vol, qui restent dans l'arène.
+        let steps = (world.gameplay.missile_lifetime / TIME_STEP) as usize;
+        let mut max_live = 0;
+        for _ in 0..steps {
+            world.step(&fire);
+            max_live = max_live.max(world.missiles().len());
+            for missile in world.missiles() {
+                let position = missile.position();
+                assert!((0.0..800.0).contains(&position.x) && (0.0..600.0).contains(&position.y));
+            }
+        }
+        assert_eq!(max_live, world.gameplay.max_missiles);
+
+        // Sans nouveau tir, tous les missiles finissent par disparaître.
+        for _ in 0..steps + 1 {
+            world.step(&PlayerInput::default());
+        }
+        assert!(world.missiles().is_empty());
+    }
+
+    #[test]
+    fn test_weapons_have_their_own_behavior() {
+        // Astéroïdes alignés au-dessus du vaisseau, et un autre hors de la ligne de tir
+        let mut world = test_world(vec![
+            Asteroid::new_at(vec2(400.0, 150.0), 15.0, Vec2::ZERO),
+            Asteroid::new_at(vec2(400.0, 100.0), 15.0, Vec2::ZERO),
+            Asteroid::new_at(vec2(700.0, 300.0), 15.0, Vec2::ZERO),
+        ]);
+        let fire = |switch_weapon| PlayerInput {
+            fire: true,
+            switch_weapon,
+            ..Default::default()
+        };
+
+        // Dispersion : trois missiles d'un coup.
+        world.step(&fire(true));
+        assert_eq!(world.spaceship().weapon(), Weapon::Spread);
+        assert_eq!(world.missiles().len(), 3);
+        assert!(world.weapon_readiness() < 1.0);
+
+        // Laser : tous les astéroïdes alignés sont touchés en un seul tir.
+        world.missiles.clear();
+        world.fire_cooldown = 0.0;
+        world.spaceship.next_weapon();
+        world.step(&fire(true));
+        assert_eq!(world.spaceship().weapon(), Weapon::Laser);
+        assert_eq!(world.asteroids().len(), 1);
+        assert_eq!(world.score(), 2 * 100);
+        assert_eq!(world.beams().len(), 1);
+
+        // Missiles guidés : le missile tourne vers le dernier astéroïde, sur la droite.
+        world.fire_cooldown = 0.0;
+        world.step(&fire(true));
+        assert_eq!(world.spaceship().weapon(), Weapon::Homing);
+        for _ in 0..30 {
+            world.step(&PlayerInput::default());
+        }
+        assert!(world.missiles()[0].velocity().x > 0.0);
+    }
+
+    #[test]
+    fn test_rapid_fire_repeats_while_trigger_is_held() {
+        let mut world = test_world(vec![Asteroid::new_at(vec2(100.0, 100.0), 15.0, Vec2::ZERO)]);
+        let held = PlayerInput {
+            fire_held: true,
+            ..Default::default()
+        };
+
+        // Le canon ne tire pas tant que la gâchette est seulement maintenue.
+        for _ in 0..30 {
+            world.step(&held);
+        }
+        assert!(world.missiles().is_empty());
+
+        // Le tir rapide tire à chaque fois que son délai est écoulé.
+        world.spaceship.next_weapon();
+        world.spaceship.next_weapon();
+        assert_eq!(world.spaceship().weapon(), Weapon::RapidFire);
+        let cooldown = Weapon::RapidFire.cooldown(world.gameplay.fire_cooldown);
+        let steps = 30;
+        for _ in 0..steps {
+            world.step(&held);
+        }
+        let expected = (steps as f32 * TIME_STEP / cooldown).ceil() as usize;
+        assert!(world.missiles().len().abs_diff(expected) <= 1);
+    }
+
+    #[test]
+    fn test_collected_powerups_apply_their_effect() {
+        let mut world = World::new(
+            800.0,
+            600.0,
+            WaveConfig::default(),
+            GameplayConfig::default(),
+            42,
+        );
+        world.asteroids = vec![Asteroid::new_at(vec2(50.0, 50.0), 15.0, Vec2::ZERO)];
+        world.spaceship.shield = 0.0;
+        let center = world.spaceship().position();
+        world.powerups = vec![
+            PowerUp::new(PowerUpKind::ShieldRecharge, center, Vec2::ZERO, 5.0),
+            PowerUp::new(PowerUpKind::ScoreMultiplier, center, Vec2::ZERO, 5.0),
+            PowerUp::new(
+                PowerUpKind::WeaponUpgrade,
+                vec2(700.0, 500.0),
+                Vec2::ZERO,
+                1.0,
+            ),
+        ];
+
+        world.step(&PlayerInput::default());
+        assert_eq!(world.spaceship().shield, Spaceship::MAX_SHIELD);
+        assert!(world.score_multiplier_time() > 0.0);
+        assert_eq!(world.powerups().len(), 1);
+
+        // Les points sont doublés tant que le multiplicateur est actif.
+        world.asteroids = vec![Asteroid::new_at(vec2(400.0, 250.0), 15.0, Vec2::ZERO)];
+        world.step(&PlayerInput {
+            fire: true,
+            ..Default::default()
+        });
+        for _ in 0..20 {
+            world.step(&PlayerInput::default());
+        }
+        assert_eq!(world.last_wave().unwrap().destruction_points, 2 * 100);
+
+        // Le bonus non ramassé finit par disparaître.
+        for _ in 0..60 {
+            world.step(&PlayerInput::default());
+        }
+        assert!(world.powerups().is_empty());
+        assert_eq!(world.spaceship().weapon(), Weapon::Cannon);
+    }
+
+    #[test]
+    fn test_combo_bonuses_and_score_never_negative() {
+        let mut world = test_world(vec![Asteroid::new_at(vec2(700.0, 500.0), 60.0, Vec2::ZERO)]);
+
+        // Le multiplicateur de combo progresse tous les `COMBO_STEP` coups au but.
+        for _ in 0..COMBO_STEP {
+            world.score_hit(100);
+        }
+        assert_eq!(world.score(), 100 * COMBO_STEP as i32);
+        assert_eq!(world.combo_multiplier(), 2);
+        world.score_hit(100);
+        assert_eq!(world.score(), 100 * COMBO_STEP as i32 + 200);
+
+        // Un missile qui finit sa course sans rien toucher remet le combo à zéro.
+        world.step(&PlayerInput {
+            fire: true,
+            ..Default::default()
+        });
+        for _ in 0..(world.gameplay.missile_lifetime / TIME_STEP) as usize + 2 {
+            world.step(&PlayerInput::default());
+        }
+        assert_eq!(world.combo_multiplier(), 1);
+
+        // Le bilan de vague compte les tirs, et un choc ne rend jamais le score négatif.
+        world.score = 0;
+        world.asteroids = vec![Asteroid::new_at(
+            world.spaceship().position(),
+            60.0,
+            Vec2::ZERO,
+        )];
+        world.step(&PlayerInput::default());
+        assert_eq!(world.score(), 0);
+        world.asteroids.clear();
+        world.step(&PlayerInput::default());
+        let summary = world.last_wave().unwrap();
+        assert_eq!(
+            (summary.shots, summary.hits, summary.accuracy_bonus),
+            (1, 0, 0)
+        );
+        assert_eq!(summary.best_combo, COMBO_STEP + 1);
+        assert_eq!(world.score(), WAVE_CLEAR_BONUS);
+    }
+
+    #[test]
+    fn test_missile_hits_a_single_asteroid() {
+        let mut world = test_world(vec![
+            Asteroid::new_at(vec2(100.0, 100.0), 15.0, Vec2::ZERO),
+            Asteroid::new_at(vec2(105.0, 100.0), 15.0, Vec2::ZERO),
+        ]);
+        world.missiles = vec![Missile::new(
+            vec2(102.0, 100.0),
+            Vec2::ZERO,
+            2.0,
+            1.0,
+            false,
+        )];
+        world.wave_summary.shots = 1;
+
+        // Le missile chevauche les deux astéroïdes mais n'en détruit qu'un.
+        world.step(&PlayerInput::default());
+        assert_eq!(world.wave_summary.hits, 1);
+        assert!(world.wave_summary.accuracy() <= 1.0);
+        assert!(world.missiles().is_empty());
+    }
+
+    #[test]
+    fn test_saucers_shoot_and_can_be_shot() {
+        let mut world = test_world(vec![
+            Asteroid::new_at(vec2(700.0, 500.0), 15.0, Vec2::ZERO),
+            Asteroid::new_at(vec2(100.0, 500.0), 15.0, Vec2::ZERO),
+        ]);
+        world.saucers = vec![Saucer::new_at(SaucerSize::Small, vec2(400.0, 200.0))];
+        world.enemy_missiles = vec![Missile::new(
+            vec2(650.0, 500.0),
+            vec2(Saucer::BULLET_SPEED, 0.0),
+            3.0,
+            Saucer::BULLET_LIFETIME,
+            false,
+        )];
+        let fire = PlayerInput {
+            fire: true,
+            ..Default::default()
+        };
+
+        // Le projectile ennemi détruit l'astéroïde sans rapporter de points, le missile du
+        // joueur abat la soucoupe.
+        world.step(&fire);
+        for _ in 0..30 {
+            world.step(&PlayerInput::default());
+        }
+        assert!(world.saucers().is_empty());
+        assert_eq!(world.score(), SaucerSize::Small.points());
+        assert_eq!(world.asteroids().len(), 1);
+
+        // Un projectile ennemi qui touche le vaisseau entame son bouclier.
+        let center = world.spaceship().position();
+        world.enemy_missiles = vec![Missile::new(center, Vec2::ZERO, 3.0, 1.0, false)];
+        world.step(&PlayerInput::default());
+        assert!(
+            world.spaceship().shield <= Spaceship::MAX_SHIELD - world.gameplay.bullet_damage + 1.0
+        );
+        // La soucoupe abattue a rapporté des vies supplémentaires, mais aucune n'est perdue.
+        let bonus_lives = (world.score() / world.gameplay.extra_life_score as i32) as u32;
+        assert_eq!(world.lives(), world.gameplay.initial_lives + bonus_lives);
+        assert!(world.enemy_missiles().is_empty());
+    }
+
+    #[test]
+    fn test_shield_absorbs_damage_by_size_and_extra_lives() {
+        let hit = |radius: f32, speed: Vec2| {
+            let mut world = test_world(vec![
+                Asteroid::new_at(vec2(420.0, 300.0), radius, speed),
+                Asteroid::new_at(vec2(50.0, 50.0), 15.0, Vec2::ZERO),
+            ]);
+            world.gameplay.shield_regen = 0.0;
+            world.step(&PlayerInput::default());
+            Spaceship::MAX_SHIELD - world.spaceship().shield
+        };
+
+        // Les gros astéroïdes et les chocs rapides font plus de dégâts.
+        let small = hit(15.0, Vec2::ZERO);
+        assert!(small > 0.0);
+        assert!(hit(30.0, Vec2::ZERO) > small);
+        assert!(hit(15.0, vec2(-100.0, 0.0)) > small);
+
+        // Une vie supplémentaire à chaque palier de score.
+        let mut world = test_world(vec![Asteroid::new_at(vec2(50.0, 50.0), 15.0, Vec2::ZERO)]);
+        world.score = 2 * world.gameplay.extra_life_score as i32;
+        world.step(&PlayerInput::default());
+        assert_eq!(world.lives(), world.gameplay.initial_lives + 2);
+        world.step(&PlayerInput::default());
+        assert_eq!(world.lives(), world.gameplay.initial_lives + 2);
+    }
+
+    #[test]
+    fn test_analog_aim_and_throttle() {
+        use std::f32::consts::PI;
+        let mut world = test_world(vec![Asteroid::new_at(vec2(50.0, 50.0), 15.0, Vec2::ZERO)]);
+
+        // Le vaisseau, orienté vers le haut, tourne vers la droite sans dépasser sa vitesse de
+        // rotation, puis garde le cap.
+        let mut input = PlayerInput::default();
+        input.set_aim(0.0);
+        world.step(&input);
+        let turned = world.spaceship().angle() + PI / 2.0;
+        assert!((turned - Spaceship::ROTATION_SPEED * TIME_STEP).abs() < 1e-4);
+        for _ in 0..(PI / 2.0 / Spaceship::ROTATION_SPEED / TIME_STEP) as usize + 2 {
+            world.step(&input);
+        }
+        assert!(world.spaceship().angle().abs() < 1e-3);
+
+        // Une poussée analogique à moitié accélère moins que la poussée pleine.
+        let mut full = test_world(vec![Asteroid::new_at(vec2(50.0, 50.0), 15.0, Vec2::ZERO)]);
+        input.aim = None;
+        input.set_throttle(0.5);
+        let thrust = PlayerInput {
+            thrust: true,
+            ..Default::default()
+        };
+        for _ in 0..30 {
+            world.step(&input);
+            full.step(&thrust);
+        }
+        let half_speed = world.spaceship().speed().length();
+        assert!(half_speed > 0.0 && half_speed < full.spaceship().speed().length());
+    }
+
+    #[test]
+    fn test_hyperspace_and_shield_bash() {
+        let mut world = test_world(vec![
+            Asteroid::new_at(vec2(500.0, 300.0), 15.0, Vec2::ZERO),
+            Asteroid::new_at(vec2(50.0, 50.0), 15.0, Vec2::ZERO),
+        ]);
+        world.gameplay.hyperspace_failure = 0.0;
+
+        // L'onde de choc consomme de l'énergie du bouclier et repousse l'astéroïde proche.
+        world.step(&PlayerInput {
+            shield_bash: true,
+            ..Default::default()
+        });
+        assert!(world.spaceship().shield < Spaceship::MAX_SHIELD - world.gameplay.bash_cost + 1.0);
+        assert!(world.asteroids()[0].speed().x > 0.0);
+        assert_eq!(world.asteroids()[1].speed(), Vec2::ZERO);
+
+        // Un second saut n'est possible qu'après le délai.
+        let jump = PlayerInput {
+            hyperspace: true,
+            ..Default::default()
+        };
+        let start = world.spaceship().position();
+        world.step(&jump);
+        let landing = world.spaceship().position();
+        assert_ne!(landing, start);
+        world.step(&jump);
+        assert_eq!(world.spaceship().position(), landing);
+        assert!(world.hyperspace_cooldown() > 0.0);
+
+        // Un saut raté détruit le vaisseau.
+        world.gameplay.hyperspace_failure = 1.0;
+        world.hyperspace_cooldown = 0.0;
+        world.step(&jump);
+        assert_eq!(world.lives(), world.gameplay.initial_lives - 1);
+        assert!(world.is_respawning());
+    }
+
+    #[test]
+    fn test_same_seed_same_game() {
+        let input = PlayerInput {
+            turn_left: true,
+            fire: true,
+            ..Default::default()
+        };
+        let mut world1 = World::new(
+            800.0,
+            600.0,
+            WaveConfig::default(),
+            GameplayConfig::default(),
+            1234,
+        );
+        let mut world2 = World::new(
+            800.0,
+            600.0,
+            WaveConfig::default(),
+            GameplayConfig::default(),
+            1234,
+        );
+
+        for _ in 0..600 {
+            assert_eq!(world1.step(&input), world2.step(&input));
+        }
+
+        assert_eq!(world1.score(), world2.score());
+        assert_eq!(world1.asteroids().len(), world2.asteroids().len());
+        for (a, b) in world1.asteroids().iter().zip(world2.asteroids()) {
+            assert_eq!(a.position(), b.position());
+            assert_eq!(a.radius(), b.radius());
+        }
+    }
+}